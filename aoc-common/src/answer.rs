@@ -0,0 +1,68 @@
+use std::fmt::Display;
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    /// The part hasn't been solved yet
+    Unsolved,
+    /// The input has no answer, like a path that never reaches its goal, or an answer that
+    /// doesn't fit in 64 bits
+    Impossible,
+    Unsigned(u64),
+    Signed(i64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Unsolved => write!(f, "unsolved"),
+            Answer::Impossible => write!(f, "impossible"),
+            Answer::Unsigned(n) => write!(f, "{n}"),
+            Answer::Signed(n) => write!(f, "{n}"),
+            Answer::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+macro_rules! impl_from {
+    ($variant:ident: $target:ty, $($from:ty),*) => {
+        $(
+            impl From<$from> for Answer {
+                fn from(value: $from) -> Self {
+                    Answer::$variant(value as $target)
+                }
+            }
+        )*
+    };
+}
+
+impl_from!(Unsigned: u64, u32, u64, usize);
+impl_from!(Signed: i64, i32, i64);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// `None` is [`Answer::Impossible`].
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Answer::Impossible, Into::into)
+    }
+}
+
+#[test]
+fn display() {
+    assert_eq!("42", Answer::from(42u32).to_string());
+    assert_eq!("-42", Answer::from(-42i64).to_string());
+    assert_eq!("abc", Answer::from("abc").to_string());
+    assert_eq!("impossible", Answer::from(None::<u64>).to_string());
+}
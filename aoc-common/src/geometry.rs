@@ -0,0 +1,244 @@
+//! Points and directions on a grid, with y pointing down like [`Grid`](crate::grid::Grid).
+//!
+//! ```text
+//! +-> x
+//! |
+//! v y
+//! ```
+
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use enum_iterator::Sequence;
+
+/// A point (or vector) that, unlike grid coords, can go negative.
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const fn new(x: i64, y: i64) -> Point {
+        Point { x, y }
+    }
+
+    /// Distance when only moving horizontally or vertically.
+    pub fn manhattan(self, other: Point) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Distance when also moving diagonally.
+    pub fn chebyshev(self, other: Point) -> u64 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// Grid coords of the point, if it isn't negative.
+    pub fn coords(self) -> Option<(usize, usize)> {
+        Some((self.x.try_into().ok()?, self.y.try_into().ok()?))
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Self {
+        Point::new(x as i64, y as i64)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Point) -> Self::Output {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Point) -> Self::Output {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Self::Output {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        Point::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Point) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Point) {
+        *self = *self - rhs;
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Sequence)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// The direction after turning a quarter clockwise.
+    pub fn rotate_right(self) -> Direction {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    /// The direction after turning a quarter counterclockwise.
+    pub fn rotate_left(self) -> Direction {
+        -self.rotate_right()
+    }
+
+    /// The unit vector pointing in this direction.
+    pub fn vector(self) -> Point {
+        match self {
+            Direction::North => Point::new(0, -1),
+            Direction::East => Point::new(1, 0),
+            Direction::South => Point::new(0, 1),
+            Direction::West => Point::new(-1, 0),
+        }
+    }
+}
+
+impl Neg for Direction {
+    type Output = Direction;
+
+    fn neg(self) -> Self::Output {
+        match self {
+            Direction::North => Direction::South,
+            Direction::East => Direction::West,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+        }
+    }
+}
+
+impl From<Direction> for Point {
+    fn from(value: Direction) -> Self {
+        value.vector()
+    }
+}
+
+/// A direction that can also be diagonal.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Sequence)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    /// The direction after turning an eighth clockwise.
+    pub fn rotate_right(self) -> Direction8 {
+        self.next().unwrap_or(Direction8::North)
+    }
+
+    /// The direction after turning an eighth counterclockwise.
+    pub fn rotate_left(self) -> Direction8 {
+        self.previous().unwrap_or(Direction8::NorthWest)
+    }
+
+    /// The vector pointing in this direction, with both coords in `-1..=1`.
+    pub fn vector(self) -> Point {
+        match self {
+            Direction8::North => Point::new(0, -1),
+            Direction8::NorthEast => Point::new(1, -1),
+            Direction8::East => Point::new(1, 0),
+            Direction8::SouthEast => Point::new(1, 1),
+            Direction8::South => Point::new(0, 1),
+            Direction8::SouthWest => Point::new(-1, 1),
+            Direction8::West => Point::new(-1, 0),
+            Direction8::NorthWest => Point::new(-1, -1),
+        }
+    }
+}
+
+impl Neg for Direction8 {
+    type Output = Direction8;
+
+    fn neg(self) -> Self::Output {
+        self.rotate_right()
+            .rotate_right()
+            .rotate_right()
+            .rotate_right()
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(value: Direction) -> Self {
+        match value {
+            Direction::North => Direction8::North,
+            Direction::East => Direction8::East,
+            Direction::South => Direction8::South,
+            Direction::West => Direction8::West,
+        }
+    }
+}
+
+impl From<Direction8> for Point {
+    fn from(value: Direction8) -> Self {
+        value.vector()
+    }
+}
+
+#[test]
+fn neg_is_opposite() {
+    for dir in enum_iterator::all::<Direction>() {
+        assert_ne!(dir, -dir);
+        assert_eq!(dir, -(-dir));
+        assert_eq!(-dir.vector(), (-dir).vector());
+    }
+    for dir in enum_iterator::all::<Direction8>() {
+        assert_ne!(dir, -dir);
+        assert_eq!(-dir.vector(), (-dir).vector());
+    }
+}
+
+#[test]
+fn rotate_round_trip() {
+    for dir in enum_iterator::all::<Direction>() {
+        assert_eq!(dir, dir.rotate_right().rotate_left());
+        assert_eq!(
+            Direction8::from(dir.rotate_right()),
+            Direction8::from(dir).rotate_right().rotate_right()
+        );
+    }
+    assert_eq!(Direction8::North, Direction8::NorthWest.rotate_right());
+    assert_eq!(Direction8::NorthWest, Direction8::North.rotate_left());
+}
+
+#[test]
+fn distances() {
+    let p1 = Point::new(-1, 2);
+    let p2 = Point::new(3, -4);
+    assert_eq!(10, p1.manhattan(p2));
+    assert_eq!(6, p1.chebyshev(p2));
+}
@@ -0,0 +1,287 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use crate::{
+    geometry::{Direction, Direction8, Point},
+    parse::{Location, ParseError},
+};
+
+/// A dense 2D grid, indexed by `(x, y)` coords.
+///
+/// ```text
+/// +-> x
+/// |
+/// v y
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Grid with the cell at `(x, y)` set to `f((x, y))`.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut((usize, usize)) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut f)
+            .collect();
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): (usize, usize)) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, coords: (usize, usize)) -> Option<&T> {
+        self.contains(coords).then(|| &self.cells[self.idx(coords)])
+    }
+
+    pub fn get_mut(&mut self, coords: (usize, usize)) -> Option<&mut T> {
+        if self.contains(coords) {
+            let idx = self.idx(coords);
+            Some(&mut self.cells[idx])
+        } else {
+            None
+        }
+    }
+
+    fn idx(&self, (x, y): (usize, usize)) -> usize {
+        y * self.width + x
+    }
+
+    /// Every cell together with its coords, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> + Clone + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(idx, cell)| ((idx % self.width, idx / self.width), cell))
+    }
+
+    /// Coords after moving from `coords` by `vector`, if they are still in the grid.
+    pub fn moved(
+        &self,
+        coords: (usize, usize),
+        vector: impl Into<Point>,
+    ) -> Option<(usize, usize)> {
+        let moved = (Point::from(coords) + vector.into()).coords()?;
+        self.contains(moved).then_some(moved)
+    }
+
+    /// Coords of the cells above, right, below and left of `coords`, that are in the grid.
+    pub fn neighbours4(&self, coords: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        enum_iterator::all::<Direction>().filter_map(move |dir| self.moved(coords, dir))
+    }
+
+    /// Coords of the cells around `coords`, including diagonals, that are in the grid.
+    pub fn neighbours8(&self, coords: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        enum_iterator::all::<Direction8>().filter_map(move |dir| self.moved(coords, dir))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        // `chunks_exact(0)` panics
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Mirror the grid along its top-left to bottom-right diagonal.
+    pub fn transpose(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |(x, y)| self[(y, x)].clone())
+    }
+
+    /// Rotate the grid a quarter turn clockwise.
+    pub fn rotate_right(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |(x, y)| {
+            self[(y, self.height - 1 - x)].clone()
+        })
+    }
+
+    /// Rotate the grid a quarter turn counterclockwise.
+    pub fn rotate_left(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |(x, y)| {
+            self[(self.width - 1 - y, x)].clone()
+        })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coords: (usize, usize)) -> &Self::Output {
+        assert!(self.contains(coords), "{coords:?} is outside of the grid");
+        &self.cells[self.idx(coords)]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, coords: (usize, usize)) -> &mut Self::Output {
+        assert!(self.contains(coords), "{coords:?} is outside of the grid");
+        let idx = self.idx(coords);
+        &mut self.cells[idx]
+    }
+}
+
+/// Parse a char grid, with every char turned into a cell by its `TryFrom<char>`.
+impl<T> FromStr for Grid<T>
+where
+    T: TryFrom<char>,
+    T::Error: Display,
+{
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let width = s.lines().next().map_or(0, |line| line.chars().count());
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for (y, line) in s.lines().enumerate() {
+            if line.chars().count() != width {
+                return Err(ParseError::invalid(
+                    s,
+                    line,
+                    format!("rows should all be {width} wide"),
+                ));
+            }
+            for (x, char) in line.chars().enumerate() {
+                let cell = T::try_from(char).map_err(|error| ParseError::Invalid {
+                    location: Location {
+                        line: y + 1,
+                        column: x + 1,
+                    },
+                    found: char.to_string(),
+                    reason: error.to_string(),
+                })?;
+                cells.push(cell);
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            cells,
+            width,
+            height,
+        })
+    }
+}
+
+/// Error for a `char` at `(x, y)` that isn't a valid cell.
+pub fn unexpected_cell((x, y): (usize, usize), char: char, expected: &str) -> ParseError {
+    ParseError::Unexpected {
+        location: Location {
+            line: y + 1,
+            column: x + 1,
+        },
+        found: char.to_string(),
+        expected: expected.to_string(),
+    }
+}
+
+/// Error for a `char` at `(x, y)` that is a valid cell, but not in this place.
+pub fn invalid_cell((x, y): (usize, usize), char: char, reason: impl Display) -> ParseError {
+    ParseError::Invalid {
+        location: Location {
+            line: y + 1,
+            column: x + 1,
+        },
+        found: char.to_string(),
+        reason: reason.to_string(),
+    }
+}
+
+#[test]
+fn grid_from_str() {
+    let grid: Grid<char> = "ab
+cd
+ef
+"
+    .parse()
+    .unwrap();
+    assert_eq!((2, 3), (grid.width(), grid.height()));
+    assert_eq!(Some(&'d'), grid.get((1, 1)));
+    assert_eq!(None, grid.get((2, 1)));
+    assert_eq!(vec![&'b', &'d', &'f'], grid.column(1).collect::<Vec<_>>());
+    assert_eq!(&['e', 'f'], grid.row(2));
+}
+
+#[test]
+fn grid_uneven_rows() {
+    let error = "ab
+c"
+    .parse::<Grid<char>>()
+    .unwrap_err();
+    assert_eq!(Location { line: 2, column: 1 }, error.location());
+}
+
+#[test]
+fn grid_neighbours() {
+    let grid = Grid::from_fn(3, 3, |coords| coords);
+    let corner = grid.neighbours8((0, 0)).collect::<Vec<_>>();
+    assert_eq!(vec![(1, 0), (1, 1), (0, 1)], corner);
+    assert_eq!(4, grid.neighbours4((1, 1)).count());
+    assert_eq!(8, grid.neighbours8((1, 1)).count());
+}
+
+#[test]
+fn grid_moved() {
+    let grid = Grid::from_fn(3, 2, |coords| coords);
+    assert_eq!(Some((2, 1)), grid.moved((1, 0), Direction8::SouthEast));
+    assert_eq!(None, grid.moved((1, 0), Direction::North));
+    assert_eq!(None, grid.moved((1, 1), Point::new(2, 0)));
+}
+
+#[test]
+fn grid_rotate() {
+    let grid: Grid<char> = "abc
+def"
+    .parse()
+    .unwrap();
+    let right: Grid<char> = "da
+eb
+fc"
+    .parse()
+    .unwrap();
+    assert_eq!(right, grid.rotate_right());
+    assert_eq!(grid, grid.rotate_right().rotate_left());
+    assert_eq!(
+        grid.rotate_right().rotate_right(),
+        grid.rotate_left().rotate_left()
+    );
+    assert_eq!(grid, grid.transpose().transpose());
+}
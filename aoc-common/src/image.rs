@@ -0,0 +1,300 @@
+//! Vector images of grid based puzzle states, for write-ups.
+//!
+//! Images are drawn in cell units: the cell at `(x, y)` covers the square from `(x, y)` to
+//! `(x + 1, y + 1)`, and is scaled up to [`CELL_SIZE`] pixels when exported.
+
+use std::fmt::Write;
+
+use crate::render::Color;
+
+/// Size of a single grid cell in the exported image, in pixels.
+pub const CELL_SIZE: u32 = 12;
+
+/// Puzzle states that can be drawn as an image.
+pub trait Draw {
+    fn draw(&self) -> Image;
+}
+
+/// Something to draw, in cell units.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Shape {
+    /// A lightly shaded background for the cell at `coords`.
+    Shade {
+        coords: (usize, usize),
+        color: Color,
+    },
+    /// Line segments through `points`, back to the first point if `closed`.
+    Polyline {
+        points: Vec<(f64, f64)>,
+        closed: bool,
+        width: f64,
+        color: Color,
+    },
+    Circle {
+        center: (f64, f64),
+        radius: f64,
+        color: Color,
+    },
+    Square {
+        center: (f64, f64),
+        size: f64,
+        color: Color,
+    },
+}
+
+/// Opacity of [`Shape::Shade`], so that whatever is drawn on top stays visible.
+const SHADE_OPACITY: f64 = 0.3;
+
+/// The center of the cell at `coords`.
+pub fn center((x, y): (usize, usize)) -> (f64, f64) {
+    (x as f64 + 0.5, y as f64 + 0.5)
+}
+
+/// Shapes on a white background, the size of a `width` by `height` grid.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    shapes: Vec<Shape>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize) -> Image {
+        Image {
+            width,
+            height,
+            shapes: Vec::new(),
+        }
+    }
+
+    /// Draw `shape` on top of the shapes drawn so far.
+    pub fn push(&mut self, shape: Shape) {
+        self.shapes.push(shape);
+    }
+
+    pub fn shapes(&self) -> &[Shape] {
+        &self.shapes
+    }
+
+    /// The image as an SVG document.
+    pub fn svg(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="0 0 {} {}">"#,
+            self.width as u32 * CELL_SIZE,
+            self.height as u32 * CELL_SIZE,
+            self.width,
+            self.height,
+        );
+        out.push_str("<rect width=\"100%\" height=\"100%\" fill=\"#ffffff\"/>\n");
+
+        for shape in &self.shapes {
+            let _ = match shape {
+                Shape::Shade {
+                    coords: (x, y),
+                    color,
+                } => writeln!(
+                    out,
+                    r#"<rect x="{x}" y="{y}" width="1" height="1" fill="{}" fill-opacity="{SHADE_OPACITY}"/>"#,
+                    color.hex(),
+                ),
+                Shape::Polyline {
+                    points,
+                    closed,
+                    width,
+                    color,
+                } => {
+                    let element = if *closed { "polygon" } else { "polyline" };
+                    let points = points
+                        .iter()
+                        .map(|(x, y)| format!("{x},{y}"))
+                        .collect::<Vec<_>>()
+                        .join(" ");
+                    writeln!(
+                        out,
+                        r#"<{element} points="{points}" fill="none" stroke="{}" stroke-width="{width}" stroke-linecap="round" stroke-linejoin="round"/>"#,
+                        color.hex(),
+                    )
+                }
+                Shape::Circle {
+                    center: (cx, cy),
+                    radius,
+                    color,
+                } => writeln!(
+                    out,
+                    r#"<circle cx="{cx}" cy="{cy}" r="{radius}" fill="{}"/>"#,
+                    color.hex(),
+                ),
+                Shape::Square {
+                    center: (cx, cy),
+                    size,
+                    color,
+                } => writeln!(
+                    out,
+                    r#"<rect x="{}" y="{}" width="{size}" height="{size}" fill="{}"/>"#,
+                    cx - size / 2.0,
+                    cy - size / 2.0,
+                    color.hex(),
+                ),
+            };
+        }
+
+        out.push_str("</svg>\n");
+        out
+    }
+
+    /// The image as a PNG file. Shapes that can't be drawn, like circles without a radius, are
+    /// left out.
+    #[cfg(feature = "png")]
+    pub fn png(&self) -> Result<Vec<u8>, String> {
+        use tiny_skia::{
+            FillRule, LineCap, LineJoin, Paint, PathBuilder, Pixmap, Rect, Stroke, Transform,
+        };
+
+        let paint = |color: Color, opacity: f64| {
+            let (r, g, b) = color.rgb();
+            let mut paint = Paint::default();
+            paint.set_color_rgba8(r, g, b, (opacity * 255.0).round() as u8);
+            paint.anti_alias = true;
+            paint
+        };
+        let rect = |x: f64, y: f64, size: f64| {
+            Rect::from_xywh(x as f32, y as f32, size as f32, size as f32)
+        };
+
+        let mut pixmap = Pixmap::new(
+            self.width as u32 * CELL_SIZE,
+            self.height as u32 * CELL_SIZE,
+        )
+        .ok_or("the image is empty")?;
+        pixmap.fill(tiny_skia::Color::WHITE);
+        let transform = Transform::from_scale(CELL_SIZE as f32, CELL_SIZE as f32);
+
+        for shape in &self.shapes {
+            match shape {
+                Shape::Shade {
+                    coords: (x, y),
+                    color,
+                } => {
+                    let Some(rect) = rect(*x as f64, *y as f64, 1.0) else {
+                        continue;
+                    };
+                    pixmap.fill_rect(rect, &paint(*color, SHADE_OPACITY), transform, None);
+                }
+                Shape::Polyline {
+                    points,
+                    closed,
+                    width,
+                    color,
+                } => {
+                    let mut builder = PathBuilder::new();
+                    for (idx, &(x, y)) in points.iter().enumerate() {
+                        if idx == 0 {
+                            builder.move_to(x as f32, y as f32);
+                        } else {
+                            builder.line_to(x as f32, y as f32);
+                        }
+                    }
+                    if *closed {
+                        builder.close();
+                    }
+                    let Some(path) = builder.finish() else {
+                        continue;
+                    };
+                    let stroke = Stroke {
+                        width: *width as f32,
+                        line_cap: LineCap::Round,
+                        line_join: LineJoin::Round,
+                        ..Stroke::default()
+                    };
+                    pixmap.stroke_path(&path, &paint(*color, 1.0), &stroke, transform, None);
+                }
+                Shape::Circle {
+                    center: (cx, cy),
+                    radius,
+                    color,
+                } => {
+                    let Some(path) =
+                        PathBuilder::from_circle(*cx as f32, *cy as f32, *radius as f32)
+                    else {
+                        continue;
+                    };
+                    pixmap.fill_path(
+                        &path,
+                        &paint(*color, 1.0),
+                        FillRule::Winding,
+                        transform,
+                        None,
+                    );
+                }
+                Shape::Square {
+                    center: (cx, cy),
+                    size,
+                    color,
+                } => {
+                    let Some(rect) = rect(cx - size / 2.0, cy - size / 2.0, *size) else {
+                        continue;
+                    };
+                    pixmap.fill_rect(rect, &paint(*color, 1.0), transform, None);
+                }
+            }
+        }
+
+        pixmap.encode_png().map_err(|error| error.to_string())
+    }
+}
+
+impl Color {
+    /// The colour as red, green and blue components, a bit darker than the usual terminal
+    /// colours so that they stand out on a white background.
+    pub fn rgb(self) -> (u8, u8, u8) {
+        match self {
+            Color::Black => (0x20, 0x20, 0x20),
+            Color::Red => (0xc8, 0x28, 0x28),
+            Color::Green => (0x2e, 0x8b, 0x3e),
+            Color::Yellow => (0xd4, 0x9a, 0x00),
+            Color::Blue => (0x28, 0x5a, 0xc8),
+            Color::Magenta => (0xa0, 0x32, 0xa0),
+            Color::Cyan => (0x1e, 0x96, 0xa0),
+            Color::White => (0x90, 0x90, 0x90),
+        }
+    }
+
+    /// The colour as `#rrggbb`.
+    pub fn hex(self) -> String {
+        let (r, g, b) = self.rgb();
+        format!("#{r:02x}{g:02x}{b:02x}")
+    }
+}
+
+#[test]
+fn svg_shapes() {
+    let mut image = Image::new(2, 1);
+    image.push(Shape::Shade {
+        coords: (1, 0),
+        color: Color::Blue,
+    });
+    image.push(Shape::Polyline {
+        points: vec![center((0, 0)), center((1, 0))],
+        closed: false,
+        width: 0.2,
+        color: Color::Green,
+    });
+    image.push(Shape::Square {
+        center: center((1, 0)),
+        size: 0.5,
+        color: Color::Red,
+    });
+
+    let svg = image.svg();
+    assert!(svg.starts_with(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="24" height="12" viewBox="0 0 2 1">"#
+    ));
+    assert!(svg.contains(
+        r##"<rect x="1" y="0" width="1" height="1" fill="#285ac8" fill-opacity="0.3"/>"##
+    ));
+    assert!(svg.contains(r#"<polyline points="0.5,0.5 1.5,0.5" "#));
+    assert!(svg.contains(r#"<rect x="1.25" y="0.25" width="0.5" height="0.5" "#));
+    assert!(svg.ends_with("</svg>\n"));
+}
@@ -0,0 +1,152 @@
+use std::{
+    env,
+    fmt::Display,
+    fs,
+    io::{self, IsTerminal, Read},
+    path::{Path, PathBuf},
+};
+
+/// Environment variable pointing to the directory with all the puzzle inputs.
+///
+/// The input for day 5 is expected at `$AOC_INPUT_DIR/day-05.txt`.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug)]
+pub enum InputError {
+    /// There is no input for the day in any of the places that were looked at
+    Missing {
+        day: u8,
+        looked_at: Option<PathBuf>,
+    },
+    Read {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Stdin(io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Missing { day, looked_at } => {
+                write!(f, "no input for day {day}, ")?;
+                match looked_at {
+                    Some(path) => write!(f, "{} does not exist", path.display())?,
+                    None => write!(f, "{INPUT_DIR_VAR} is not set")?,
+                }
+                write!(
+                    f,
+                    "\npass it with --input <path>, pipe it to stdin or put it in ${INPUT_DIR_VAR}/{}",
+                    file_name(*day)
+                )
+            }
+            InputError::Read { path, source } => {
+                write!(f, "could not read {}: {source}", path.display())
+            }
+            InputError::Stdin(source) => write!(f, "could not read stdin: {source}"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::Missing { .. } => None,
+            InputError::Read { source, .. } | InputError::Stdin(source) => Some(source),
+        }
+    }
+}
+
+/// Name of the input file of `day` in [`INPUT_DIR_VAR`].
+pub fn file_name(day: u8) -> String {
+    format!("day-{day:02}.txt")
+}
+
+/// Load the input of `day` from the first place that has one:
+/// 1. `path`, if given
+/// 2. stdin, if something is piped into it
+/// 3. [`INPUT_DIR_VAR`]
+pub fn load(day: u8, path: Option<&Path>) -> Result<String, InputError> {
+    if let Some(path) = path {
+        return read(path);
+    }
+
+    if let Some(input) = read_stdin()? {
+        return Ok(input);
+    }
+
+    load_from_dir(day)
+}
+
+/// Load the input of `day` from [`INPUT_DIR_VAR`].
+pub fn load_from_dir(day: u8) -> Result<String, InputError> {
+    let Some(dir) = env::var_os(INPUT_DIR_VAR) else {
+        return Err(InputError::Missing {
+            day,
+            looked_at: None,
+        });
+    };
+
+    load_from(Path::new(&dir), day)
+}
+
+fn load_from(dir: &Path, day: u8) -> Result<String, InputError> {
+    let path = dir.join(file_name(day));
+    if !path.is_file() {
+        return Err(InputError::Missing {
+            day,
+            looked_at: Some(path),
+        });
+    }
+
+    read(&path)
+}
+
+/// The real input of `day` for the `real_input` tests, or `None` if it's missing,
+/// in which case the test should be skipped.
+///
+/// # Panics
+///
+/// If the input exists, but can't be read.
+pub fn real_input(day: u8) -> Option<String> {
+    match load_from_dir(day) {
+        Ok(input) => Some(input),
+        Err(error @ InputError::Missing { .. }) => {
+            eprintln!("skipping test, {error}");
+            None
+        }
+        Err(error) => panic!("{error}"),
+    }
+}
+
+fn read(path: &Path) -> Result<String, InputError> {
+    fs::read_to_string(path).map_err(|source| InputError::Read {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// `None` if stdin is a terminal, or nothing was piped into it.
+fn read_stdin() -> Result<Option<String>, InputError> {
+    let mut stdin = io::stdin();
+    if stdin.is_terminal() {
+        return Ok(None);
+    }
+
+    let mut input = String::new();
+    stdin
+        .read_to_string(&mut input)
+        .map_err(InputError::Stdin)?;
+    Ok((!input.is_empty()).then_some(input))
+}
+
+#[test]
+fn missing_file() {
+    let dir = env::temp_dir().join("aoc-missing-inputs");
+
+    let error = load_from(&dir, 25).unwrap_err();
+    assert!(matches!(
+        error,
+        InputError::Missing { day: 25, looked_at: Some(path) } if path == dir.join("day-25.txt")
+    ));
+}
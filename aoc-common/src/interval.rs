@@ -0,0 +1,180 @@
+//! Sets of numbers, kept as the ranges they're made of instead of one by one.
+
+use std::ops::Range;
+
+/// A set of values kept as sorted ranges that don't overlap or touch, and aren't empty.
+///
+/// Ranges that overlap or touch are merged when they're added, so `0..2` and `2..4` become
+/// `0..4`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T>(Vec<Range<T>>);
+
+impl<T: Copy + Ord> IntervalSet<T> {
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet(Vec::new())
+    }
+
+    /// The ranges in the set, from low to high.
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The lowest value in the set.
+    pub fn min(&self) -> Option<T> {
+        self.0.first().map(|range| range.start)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let idx = self.0.partition_point(|range| range.end <= value);
+        self.0.get(idx).is_some_and(|range| range.start <= value)
+    }
+
+    /// Add the values in `range` to the set.
+    pub fn insert(&mut self, range: Range<T>) {
+        let ranges = std::mem::take(&mut self.0);
+        self.0 = coalesce(ranges.into_iter().chain([range]).collect());
+    }
+
+    /// Values in either of the sets.
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        self.0.iter().chain(&other.0).cloned().collect()
+    }
+
+    /// Values in both of the sets.
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut out = Vec::new();
+        let (mut left, mut right) = (self.0.iter().peekable(), other.0.iter().peekable());
+        while let (Some(a), Some(b)) = (left.peek(), right.peek()) {
+            let overlap = a.start.max(b.start)..a.end.min(b.end);
+            if overlap.start < overlap.end {
+                out.push(overlap);
+            }
+            // whichever ends first can't overlap anything else in the other set
+            if a.end <= b.end {
+                left.next();
+            } else {
+                right.next();
+            }
+        }
+        IntervalSet(out)
+    }
+
+    /// Values in this set, but not in `other`.
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut out = Vec::new();
+        let mut removed = other.0.iter().peekable();
+        for range in &self.0 {
+            let mut start = range.start;
+            while let Some(hole) = removed.peek() {
+                if hole.end <= start {
+                    removed.next();
+                    continue;
+                }
+                if hole.start >= range.end {
+                    break;
+                }
+                if start < hole.start {
+                    out.push(start..hole.start);
+                }
+                start = hole.end;
+                if hole.end > range.end {
+                    // the hole might cut into the next range too
+                    break;
+                }
+                removed.next();
+            }
+            if start < range.end {
+                out.push(start..range.end);
+            }
+        }
+        IntervalSet(out)
+    }
+
+    /// The ranges in the set, cut in two at every one of `breakpoints` that falls inside one.
+    pub fn split(&self, breakpoints: impl IntoIterator<Item = T>) -> Vec<Range<T>> {
+        let mut breakpoints = breakpoints.into_iter().collect::<Vec<_>>();
+        breakpoints.sort_unstable();
+        breakpoints.dedup();
+
+        let mut out = Vec::new();
+        for range in &self.0 {
+            let mut start = range.start;
+            let first = breakpoints.partition_point(|&point| point <= range.start);
+            for &point in breakpoints[first..]
+                .iter()
+                .take_while(|&&point| point < range.end)
+            {
+                out.push(start..point);
+                start = point;
+            }
+            out.push(start..range.end);
+        }
+        out
+    }
+}
+
+impl<T: Copy + Ord> Default for IntervalSet<T> {
+    fn default() -> IntervalSet<T> {
+        IntervalSet::new()
+    }
+}
+
+impl<T: Copy + Ord> From<Range<T>> for IntervalSet<T> {
+    fn from(range: Range<T>) -> IntervalSet<T> {
+        [range].into_iter().collect()
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> IntervalSet<T> {
+        IntervalSet(coalesce(iter.into_iter().collect()))
+    }
+}
+
+/// Sort `ranges` and merge the ones that overlap or touch, leaving out the empty ones.
+fn coalesce<T: Copy + Ord>(mut ranges: Vec<Range<T>>) -> Vec<Range<T>> {
+    ranges.retain(|range| range.start < range.end);
+    ranges.sort_unstable_by_key(|range| range.start);
+
+    let mut out: Vec<Range<T>> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match out.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => out.push(range),
+        }
+    }
+    out
+}
+
+#[test]
+fn coalesce_ranges() {
+    let set: IntervalSet<u64> = [8..9, 0..2, 5..5, 2..4, 3..6].into_iter().collect();
+    assert_eq!(&[0..6, 8..9], set.ranges());
+    assert!(set.contains(5));
+    assert!(!set.contains(6));
+    assert_eq!(Some(0), set.min());
+}
+
+#[test]
+fn set_operations() {
+    let a: IntervalSet<u64> = [0..10, 20..30].into_iter().collect();
+    let b: IntervalSet<u64> = [5..8, 9..25, 28..40].into_iter().collect();
+
+    assert_eq!(vec![0..40], a.union(&b).ranges());
+    assert_eq!(&[5..8, 9..10, 20..25, 28..30], a.intersection(&b).ranges());
+    assert_eq!(&[0..5, 8..9, 25..28], a.difference(&b).ranges());
+    assert_eq!(&[10..20, 30..40], b.difference(&a).ranges());
+}
+
+#[test]
+fn split_at_breakpoints() {
+    let set: IntervalSet<u64> = [0..10, 20..30].into_iter().collect();
+    assert_eq!(
+        vec![0..5, 5..10, 20..25, 25..30],
+        set.split([25, 0, 5, 10, 15, 5])
+    );
+}
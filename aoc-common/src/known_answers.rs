@@ -0,0 +1,205 @@
+//! Answers that are known to be correct, kept in a local file next to the puzzle inputs instead of
+//! in the source, so that they don't end up in git and everyone can check their own inputs.
+//!
+//! The answers are kept by day, by a hash of the input and by part:
+//!
+//! ```json
+//! {
+//!   "day-05": {
+//!     "9c4f0e1b2a3d4c5e": {
+//!       "part1": "35",
+//!       "part2": "46"
+//!     }
+//!   }
+//! }
+//! ```
+
+use std::{
+    collections::BTreeMap,
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::input::{self, INPUT_DIR_VAR};
+
+/// Name of the file with the known answers in [`INPUT_DIR_VAR`].
+pub const FILE_NAME: &str = "answers.json";
+
+#[derive(Debug)]
+pub enum KnownAnswersError {
+    Read { path: PathBuf, source: io::Error },
+    Invalid { path: PathBuf, reason: String },
+    Write { path: PathBuf, source: io::Error },
+}
+
+impl Display for KnownAnswersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KnownAnswersError::Read { path, source } => {
+                write!(f, "could not read {}: {source}", path.display())
+            }
+            KnownAnswersError::Invalid { path, reason } => {
+                write!(
+                    f,
+                    "{} is not a valid answers file: {reason}",
+                    path.display()
+                )
+            }
+            KnownAnswersError::Write { path, source } => {
+                write!(f, "could not write {}: {source}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for KnownAnswersError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            KnownAnswersError::Read { source, .. } | KnownAnswersError::Write { source, .. } => {
+                Some(source)
+            }
+            KnownAnswersError::Invalid { .. } => None,
+        }
+    }
+}
+
+/// The known answers, by day, input hash and part.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KnownAnswers(BTreeMap<(u8, String, u8), String>);
+
+impl KnownAnswers {
+    /// Where the known answers are kept, `None` if [`INPUT_DIR_VAR`] isn't set.
+    pub fn path() -> Option<PathBuf> {
+        env::var_os(INPUT_DIR_VAR).map(|dir| Path::new(&dir).join(FILE_NAME))
+    }
+
+    /// The answers in `path`, or no answers at all if it doesn't exist yet.
+    pub fn load(path: &Path) -> Result<KnownAnswers, KnownAnswersError> {
+        if !path.exists() {
+            return Ok(KnownAnswers::default());
+        }
+        let json = fs::read_to_string(path).map_err(|source| KnownAnswersError::Read {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        KnownAnswers::from_json(&json).map_err(|reason| KnownAnswersError::Invalid {
+            path: path.to_path_buf(),
+            reason,
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), KnownAnswersError> {
+        fs::write(path, self.to_json()).map_err(|source| KnownAnswersError::Write {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    /// The answer to `part` of `day` for `input`, if it's known.
+    pub fn get(&self, day: u8, part: u8, input: &str) -> Option<&str> {
+        self.0
+            .get(&(day, input_hash(input), part))
+            .map(String::as_str)
+    }
+
+    /// Remember `answer` as the correct answer to `part` of `day` for `input`.
+    pub fn insert(&mut self, day: u8, part: u8, input: &str, answer: String) {
+        self.0.insert((day, input_hash(input), part), answer);
+    }
+
+    fn from_json(json: &str) -> Result<KnownAnswers, String> {
+        let days: Json = serde_json::from_str(json).map_err(|error| error.to_string())?;
+
+        let mut answers = BTreeMap::new();
+        for (day_key, inputs) in days {
+            let day = day_key
+                .strip_prefix("day-")
+                .and_then(|day| day.parse().ok())
+                .ok_or_else(|| format!("{day_key:?} should be like \"day-05\""))?;
+            for (hash, parts) in inputs {
+                for (part_key, answer) in parts {
+                    let part = part_key
+                        .strip_prefix("part")
+                        .and_then(|part| part.parse().ok())
+                        .filter(|part| (1..=2).contains(part))
+                        .ok_or_else(|| format!("{part_key:?} should be \"part1\" or \"part2\""))?;
+                    answers.insert((day, hash.clone(), part), answer);
+                }
+            }
+        }
+        Ok(KnownAnswers(answers))
+    }
+
+    fn to_json(&self) -> String {
+        let mut days = Json::new();
+        for ((day, hash, part), answer) in &self.0 {
+            days.entry(format!("day-{day:02}"))
+                .or_default()
+                .entry(hash.clone())
+                .or_default()
+                .insert(format!("part{part}"), answer.clone());
+        }
+
+        let mut json =
+            serde_json::to_string_pretty(&days).expect("maps of strings can be serialized");
+        json.push('\n');
+        json
+    }
+}
+
+/// How the answers are kept in the file, answers by part by input hash by day.
+type Json = BTreeMap<String, BTreeMap<String, BTreeMap<String, String>>>;
+
+/// Hash of `input` that stays the same between runs and compiler versions (64 bit FNV-1a).
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("{hash:016x}")
+}
+
+/// The real input of `day` and the known answer to its `part`, for the `real_input` tests. `None`
+/// if either of them is missing, in which case the test should be skipped.
+///
+/// # Panics
+///
+/// If the input or the known answers exist, but can't be read.
+pub fn real_answer(day: u8, part: u8) -> Option<(String, String)> {
+    let input = input::real_input(day)?;
+    let path = KnownAnswers::path()?;
+    let known = KnownAnswers::load(&path).unwrap_or_else(|error| panic!("{error}"));
+
+    match known.get(day, part, &input) {
+        Some(answer) => Some((input, answer.to_string())),
+        None => {
+            eprintln!(
+                "skipping test, the answer to part {part} of day {day} isn't known yet, \
+                check it with `aoc verify` to remember it"
+            );
+            None
+        }
+    }
+}
+
+#[test]
+fn json_round_trip() {
+    let mut known = KnownAnswers::default();
+    known.insert(5, 1, "seeds: 79 14", "35".to_string());
+    known.insert(5, 2, "seeds: 79 14", "46".to_string());
+    known.insert(1, 1, "1abc2", "12".to_string());
+
+    let json = known.to_json();
+    assert!(json.starts_with("{\n  \"day-01\": {\n"));
+    assert_eq!(Ok(known.clone()), KnownAnswers::from_json(&json));
+    assert_eq!(Some("46"), known.get(5, 2, "seeds: 79 14"));
+    assert_eq!(None, known.get(5, 2, "seeds: 79 15"));
+}
+
+#[test]
+fn invalid_json() {
+    let error = KnownAnswers::from_json(r#"{"day-05": {"abc": {"part3": "35"}}}"#).unwrap_err();
+    assert_eq!(r#""part3" should be "part1" or "part2""#, error);
+}
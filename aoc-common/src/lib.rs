@@ -0,0 +1,17 @@
+//! Helpers shared between the different days.
+
+pub mod answer;
+pub mod generate;
+pub mod geometry;
+pub mod grid;
+pub mod image;
+pub mod input;
+pub mod interval;
+pub mod known_answers;
+pub mod parse;
+pub mod playback;
+pub mod render;
+pub mod solution;
+
+pub use answer::Answer;
+pub use solution::{DynSolution, Solution};
@@ -0,0 +1,390 @@
+use std::{collections::HashSet, fmt::Display};
+
+use nom::{
+    character::complete::{line_ending, space0, u32},
+    error::{ErrorKind, ParseError as NomParseError},
+    multi::fold_many1,
+    sequence::preceded,
+    IResult, Parser,
+};
+use nom_supreme::error::{BaseErrorKind, ErrorTree, StackContext};
+
+/// A position in the puzzle input, both counting from 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Location {
+    /// Location of `at`, which should be a slice of `input`.
+    ///
+    /// If it isn't, `at` is assumed to be the end of `input`, like the remaining input of a parser.
+    pub fn of(input: &str, at: &str) -> Location {
+        let offset = (at.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset + at.len() <= input.len())
+            .unwrap_or(input.len().saturating_sub(at.len()));
+        let before = input.get(..offset).unwrap_or(input);
+
+        let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+        Location {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+/// Why a puzzle input couldn't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// `found` (up to the end of its line) is not the `expected` text
+    Unexpected {
+        location: Location,
+        found: String,
+        expected: String,
+    },
+    /// The input ended while `expected` was still missing
+    UnexpectedEnd {
+        location: Location,
+        expected: String,
+    },
+    /// `found` has the right shape, but not a valid value
+    Invalid {
+        location: Location,
+        found: String,
+        reason: String,
+    },
+    /// `source` happened while parsing the `context` that started at `location`
+    Context {
+        location: Location,
+        context: String,
+        source: Box<ParseError>,
+    },
+}
+
+impl ParseError {
+    /// `expected` was expected at `at`, a slice of `input`.
+    pub fn expected(input: &str, at: &str, expected: impl Into<String>) -> ParseError {
+        let location = Location::of(input, at);
+        let expected = expected.into();
+
+        if at.is_empty() {
+            ParseError::UnexpectedEnd { location, expected }
+        } else {
+            ParseError::Unexpected {
+                location,
+                found: rest_of_line(at),
+                expected,
+            }
+        }
+    }
+
+    /// `at`, a slice of `input`, is invalid because of `reason`.
+    pub fn invalid(input: &str, at: &str, reason: impl Display) -> ParseError {
+        ParseError::Invalid {
+            location: Location::of(input, at),
+            found: rest_of_line(at),
+            reason: reason.to_string(),
+        }
+    }
+
+    /// Where the error happened, ignoring the context it happened in.
+    pub fn location(&self) -> Location {
+        match self {
+            ParseError::Unexpected { location, .. }
+            | ParseError::UnexpectedEnd { location, .. }
+            | ParseError::Invalid { location, .. } => *location,
+            ParseError::Context { source, .. } => source.location(),
+        }
+    }
+
+    /// The contexts the error happened in, innermost first.
+    pub fn contexts(&self) -> Vec<(Location, &str)> {
+        let mut contexts = Vec::new();
+        let mut error = self;
+        while let ParseError::Context {
+            location,
+            context,
+            source,
+        } = error
+        {
+            contexts.push((*location, context.as_str()));
+            error = source;
+        }
+        contexts.reverse();
+        contexts
+    }
+
+    /// What went wrong, without the location.
+    fn message(&self) -> String {
+        match self {
+            ParseError::Unexpected {
+                found, expected, ..
+            } if found.is_empty() => format!("expected {expected}, found end of line"),
+            ParseError::Unexpected {
+                found, expected, ..
+            } => format!("expected {expected}, found {found:?}"),
+            ParseError::UnexpectedEnd { expected, .. } => {
+                format!("expected {expected}, found end of input")
+            }
+            ParseError::Invalid { found, reason, .. } => format!("{reason}, in {found:?}"),
+            ParseError::Context { source, .. } => source.message(),
+        }
+    }
+
+    /// A multi-line report of the error, showing where it is in `input`.
+    pub fn report<'a>(&'a self, input: &'a str) -> Report<'a> {
+        Report { error: self, input }
+    }
+}
+
+fn rest_of_line(at: &str) -> String {
+    at.lines().next().unwrap_or_default().to_string()
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::Context {
+                location,
+                context,
+                source,
+            } => write!(f, "{source}, while parsing {context} at {location}"),
+            error => write!(f, "{}: {}", error.location(), error.message()),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A [`ParseError`] together with the input it happened in, see [`ParseError::report`].
+///
+/// ```text
+/// error: expected a number, found "x 3 | 1"
+///  --> line 2, column 15
+///   |
+/// 2 | Card 2: 13 32 x 3 | 1
+///   |               ^
+///   = while parsing winning numbers at line 2, column 8
+///   = while parsing card at line 2, column 1
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Report<'a> {
+    error: &'a ParseError,
+    input: &'a str,
+}
+
+impl Display for Report<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let location = self.error.location();
+        let line = self
+            .input
+            .lines()
+            .nth(location.line - 1)
+            .unwrap_or_default();
+        let margin = " ".repeat(location.line.to_string().len());
+
+        writeln!(f, "error: {}", self.error.message())?;
+        writeln!(f, "{margin}--> {location}")?;
+        writeln!(f, "{margin} |")?;
+        writeln!(f, "{} | {line}", location.line)?;
+        write!(f, "{margin} | {:>1$}", "^", location.column)?;
+        for (location, context) in self.error.contexts() {
+            write!(f, "\n{margin} = while parsing {context} at {location}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Errors of nom parsers, which know where in the input they went wrong.
+pub trait IntoParseError<'a> {
+    /// `input` is the full input that was given to the parser.
+    fn into_parse_error(self, input: &'a str) -> ParseError;
+}
+
+impl<'a> IntoParseError<'a> for nom::error::Error<&'a str> {
+    fn into_parse_error(self, input: &'a str) -> ParseError {
+        ParseError::expected(input, self.input, describe(self.code))
+    }
+}
+
+impl<'a> IntoParseError<'a> for ErrorTree<&'a str> {
+    fn into_parse_error(self, input: &'a str) -> ParseError {
+        match self {
+            ErrorTree::Base { location, kind } => match kind {
+                BaseErrorKind::Expected(expectation) => {
+                    ParseError::expected(input, location, expectation.to_string())
+                }
+                BaseErrorKind::Kind(kind) => ParseError::expected(input, location, describe(kind)),
+                BaseErrorKind::External(error) => ParseError::invalid(input, location, error),
+            },
+            ErrorTree::Stack { base, contexts } => {
+                // contexts are innermost first, so wrap the base error from the inside out
+                contexts
+                    .into_iter()
+                    .fold(
+                        base.into_parse_error(input),
+                        |error, (at, context)| match context {
+                            StackContext::Context(context) => ParseError::Context {
+                                location: Location::of(input, at),
+                                context: context.to_string(),
+                                source: Box::new(error),
+                            },
+                            StackContext::Kind(_) => error,
+                        },
+                    )
+            }
+            // the alternative that got the furthest is probably the one that was meant
+            ErrorTree::Alt(alternatives) => alternatives
+                .into_iter()
+                .map(|alternative| alternative.into_parse_error(input))
+                .max_by_key(ParseError::location)
+                .unwrap_or_else(|| ParseError::expected(input, input, "anything")),
+        }
+    }
+}
+
+/// What a nom parser that failed with `kind` was looking for.
+fn describe(kind: ErrorKind) -> String {
+    match kind {
+        ErrorKind::Digit => "a number".to_string(),
+        ErrorKind::Alpha => "a letter".to_string(),
+        ErrorKind::AlphaNumeric => "a letter or digit".to_string(),
+        ErrorKind::Space | ErrorKind::MultiSpace => "a space".to_string(),
+        ErrorKind::CrLf => "a line ending".to_string(),
+        ErrorKind::Eof => "end of input".to_string(),
+        kind => format!("{kind:?}").to_lowercase(),
+    }
+}
+
+/// Check the result of a top level parser, which should have consumed all of `input`.
+pub fn finish<'a, O, E: IntoParseError<'a>>(
+    input: &'a str,
+    result: IResult<&'a str, O, E>,
+) -> Result<O, ParseError> {
+    match result {
+        Ok(("", output)) => Ok(output),
+        Ok((rest, _)) => Err(ParseError::expected(input, rest, "end of input")),
+        Err(nom::Err::Error(error) | nom::Err::Failure(error)) => {
+            Err(error.into_parse_error(input))
+        }
+        Err(nom::Err::Incomplete(_)) => Err(ParseError::expected(
+            input,
+            &input[input.len()..],
+            "more input",
+        )),
+    }
+}
+
+/// Parse every line of the input with `line`, allowing a final line ending.
+///
+/// Unlike `separated_list1(line_ending, line)`, this doesn't stop at the first line that can't be
+/// parsed, but returns its error, so that it can be reported.
+pub fn lines<'a, O, E, P>(mut line: P) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>, E>
+where
+    P: Parser<&'a str, O, E>,
+    E: NomParseError<&'a str>,
+{
+    move |mut input| {
+        let mut output = Vec::new();
+        loop {
+            let (rest, value) = line.parse(input)?;
+            output.push(value);
+
+            match line_ending::<_, E>(rest) {
+                Ok(("", _)) => return Ok(("", output)),
+                Ok((next_line, _)) => input = next_line,
+                Err(_) => return Ok((rest, output)),
+            }
+        }
+    }
+}
+
+/// Parse a space separated list of numbers into a set.
+pub fn number_set<'a, E: NomParseError<&'a str>>(
+    input: &'a str,
+) -> IResult<&'a str, HashSet<u32>, E> {
+    let parse_one = preceded(space0, u32);
+
+    fold_many1(parse_one, HashSet::new, |mut set, num| {
+        set.insert(num);
+        set
+    })(input)
+}
+
+#[test]
+fn location_of_slice() {
+    let input = "abc
+def
+ghi";
+    let at = &input[5..];
+    assert_eq!(Location { line: 2, column: 2 }, Location::of(input, at));
+    assert_eq!(Location { line: 3, column: 4 }, Location::of(input, ""));
+}
+
+#[test]
+fn finish_incomplete() {
+    let input = "12 34";
+    let result: IResult<&str, u32, nom::error::Error<&str>> = u32(input);
+    let expected = Err(ParseError::Unexpected {
+        location: Location { line: 1, column: 3 },
+        found: " 34".to_string(),
+        expected: "end of input".to_string(),
+    });
+    assert_eq!(expected, finish(input, result));
+}
+
+#[test]
+fn number_set_with_padding() {
+    let expected = HashSet::from([1, 21, 53]);
+    let result: IResult<_, _, ()> = number_set(" 1 21  53");
+    assert_eq!(Ok(("", expected)), result);
+}
+
+#[test]
+fn lines_error_in_last_line() {
+    let input = "1 2
+x 3
+";
+    let mut parser = lines(nom_supreme::ParserExt::context(
+        number_set::<ErrorTree<&str>>,
+        "numbers",
+    ));
+    let error = finish(input, parser(input)).unwrap_err();
+
+    assert_eq!(Location { line: 2, column: 1 }, error.location());
+    assert_eq!(
+        vec![(Location { line: 2, column: 1 }, "numbers")],
+        error.contexts()
+    );
+}
+
+#[test]
+fn report_with_caret() {
+    let input = "Card 1: 41 48 | 83 86
+Card 2: 13 32 x 3 | 1";
+    let error = ParseError::Context {
+        location: Location { line: 2, column: 1 },
+        context: "card".to_string(),
+        source: Box::new(ParseError::Context {
+            location: Location { line: 2, column: 8 },
+            context: "winning numbers".to_string(),
+            source: Box::new(ParseError::expected(input, &input[36..], "a number")),
+        }),
+    };
+    let expected = r#"error: expected a number, found "x 3 | 1"
+ --> line 2, column 15
+  |
+2 | Card 2: 13 32 x 3 | 1
+  |               ^
+  = while parsing winning numbers at line 2, column 8
+  = while parsing card at line 2, column 1"#;
+    assert_eq!(expected, error.report(input).to_string());
+}
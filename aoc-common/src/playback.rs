@@ -0,0 +1,237 @@
+//! Step by step recordings of simulations, to play back or share.
+
+use std::fmt::Write;
+
+use crate::{grid::Grid, render::Glyph};
+
+/// Simulations whose steps can be recorded.
+pub trait Animate {
+    fn record(&self) -> Recording;
+}
+
+/// The pictures of every step of a simulation.
+///
+/// Only the glyphs that change between frames are stored, so that long simulations on big grids
+/// still fit in memory. A full picture is kept whenever the changes since the last one add up to
+/// a whole picture, so getting any frame only replays about one picture's worth of changes.
+#[derive(Debug, Clone)]
+pub struct Recording {
+    /// Full pictures of some of the frames with their index, starting with the initial frame
+    keyframes: Vec<(usize, Grid<Glyph>)>,
+    /// Frame `idx + 1` is frame `idx` with `steps[idx]` applied
+    steps: Vec<Vec<((usize, usize), Glyph)>>,
+    labels: Vec<String>,
+    last: Grid<Glyph>,
+    /// Glyphs changed since the last keyframe
+    changed_since_keyframe: usize,
+}
+
+impl Recording {
+    pub fn new(initial: Grid<Glyph>, label: impl Into<String>) -> Recording {
+        Recording {
+            last: initial.clone(),
+            keyframes: vec![(0, initial)],
+            steps: Vec::new(),
+            labels: vec![label.into()],
+            changed_since_keyframe: 0,
+        }
+    }
+
+    /// Add the next frame, which should have the same size as the others.
+    pub fn push(&mut self, frame: Grid<Glyph>, label: impl Into<String>) {
+        let changes = frame
+            .iter()
+            .filter(|&(coords, glyph)| self.last.get(coords) != Some(glyph))
+            .map(|(coords, &glyph)| (coords, glyph))
+            .collect();
+        self.last = frame;
+        self.push_step(changes, label.into());
+    }
+
+    /// Add the next frame, as the glyphs that changed since the last one.
+    pub fn push_changes(
+        &mut self,
+        changes: Vec<((usize, usize), Glyph)>,
+        label: impl Into<String>,
+    ) {
+        for &(coords, glyph) in &changes {
+            self.last[coords] = glyph;
+        }
+        self.push_step(changes, label.into());
+    }
+
+    /// Add the `changes` that lead to `last`, and a keyframe if enough has changed since the
+    /// last one.
+    fn push_step(&mut self, changes: Vec<((usize, usize), Glyph)>, label: String) {
+        self.changed_since_keyframe += changes.len();
+        self.steps.push(changes);
+        self.labels.push(label);
+
+        if self.changed_since_keyframe >= (self.width() * self.height()).max(1) {
+            self.keyframes.push((self.len() - 1, self.last.clone()));
+            self.changed_since_keyframe = 0;
+        }
+    }
+
+    /// Number of frames, including the initial one.
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    /// Always `false`, there is at least the initial frame.
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    pub fn label(&self, idx: usize) -> &str {
+        &self.labels[idx]
+    }
+
+    /// The picture of frame `idx`, from the last keyframe before it.
+    pub fn frame(&self, idx: usize) -> Grid<Glyph> {
+        let keyframe = self.keyframes.partition_point(|&(start, _)| start <= idx) - 1;
+        let (start, ref keyframe) = self.keyframes[keyframe];
+        let mut frame = keyframe.clone();
+        for changes in &self.steps[start..idx] {
+            for &(coords, glyph) in changes {
+                frame[coords] = glyph;
+            }
+        }
+        frame
+    }
+
+    /// The glyphs that changed from frame `idx - 1` to frame `idx`.
+    pub fn changes(&self, idx: usize) -> &[((usize, usize), Glyph)] {
+        match idx {
+            0 => &[],
+            idx => &self.steps[idx - 1],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.last.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.last.height()
+    }
+
+    /// The recording as an [asciicast v2](https://docs.asciinema.org/manual/asciicast/v2/) file,
+    /// playing `fps` frames per second.
+    pub fn asciicast(&self, fps: f64) -> String {
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            r#"{{"version": 2, "width": {}, "height": {}}}"#,
+            self.width(),
+            self.height() + 1
+        );
+
+        let mut frame = self.frame(0);
+        let mut screen = format!("\x1b[2J{}", ansi_screen(&frame));
+        for idx in 0..self.len() {
+            for &(coords, glyph) in self.changes(idx) {
+                frame[coords] = glyph;
+            }
+            if idx > 0 {
+                screen = ansi_changes(self.changes(idx));
+            }
+            screen.push_str(&status_line(self.height(), self.label(idx)));
+
+            let time = idx as f64 / fps;
+            let _ = writeln!(out, r#"[{time:.3}, "o", "{}"]"#, json_escape(&screen));
+        }
+        out
+    }
+}
+
+/// Draw `picture` from the top left of the screen, using cursor movements instead of newlines.
+pub fn ansi_screen(picture: &Grid<Glyph>) -> String {
+    let mut out = String::new();
+    for (y, row) in crate::render::ansi(picture).lines().enumerate() {
+        let _ = write!(out, "\x1b[{};1H{row}", y + 1);
+    }
+    out
+}
+
+/// Redraw only the `changes` of a picture drawn by [`ansi_screen`].
+pub fn ansi_changes(changes: &[((usize, usize), Glyph)]) -> String {
+    let mut out = String::new();
+    for &((x, y), glyph) in changes {
+        let _ = write!(out, "\x1b[{};{}H", y + 1, x + 1);
+        out.push_str(&crate::render::ansi_glyph(glyph));
+    }
+    out
+}
+
+/// `label` on the line below a picture of `height` rows.
+pub fn status_line(height: usize, label: &str) -> String {
+    format!("\x1b[{};1H\x1b[2K{label}", height + 1)
+}
+
+fn json_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            c if c.is_control() => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[test]
+fn recording_only_stores_changes() {
+    let blank = Grid::from_fn(3, 2, |_| Glyph::plain('.'));
+    let mut recording = Recording::new(blank.clone(), "start");
+    let mut frame = blank.clone();
+    frame[(1, 1)] = Glyph::plain('#');
+    recording.push(frame.clone(), "one");
+    recording.push(frame.clone(), "same");
+
+    assert_eq!(3, recording.len());
+    assert_eq!(&[((1, 1), Glyph::plain('#'))], recording.changes(1));
+    assert!(recording.changes(2).is_empty());
+    assert_eq!(blank, recording.frame(0));
+    assert_eq!(frame, recording.frame(2));
+    assert_eq!("same", recording.label(2));
+}
+
+#[test]
+fn frames_from_keyframes() {
+    let blank = Grid::from_fn(2, 2, |_| Glyph::plain('.'));
+    let mut recording = Recording::new(blank.clone(), "0");
+    // one glyph changes in every frame, so every 4th frame is a keyframe
+    let mut frames = vec![blank];
+    for idx in 1..20 {
+        let mut frame = frames[idx - 1].clone();
+        let coords = (idx % 2, idx / 2 % 2);
+        frame[coords] = Glyph::plain(char::from(b'a' + idx as u8));
+        recording.push(frame.clone(), idx.to_string());
+        frames.push(frame);
+    }
+
+    let starts = recording.keyframes.iter().map(|&(start, _)| start);
+    assert_eq!(vec![0, 4, 8, 12, 16], starts.collect::<Vec<_>>());
+    for (idx, frame) in frames.iter().enumerate() {
+        assert_eq!(frame, &recording.frame(idx), "frame {idx}");
+    }
+}
+
+#[test]
+fn asciicast_events() {
+    let mut recording = Recording::new(Grid::from_fn(1, 1, |_| Glyph::plain('.')), "a");
+    recording.push(Grid::from_fn(1, 1, |_| Glyph::plain('#')), "b");
+
+    let cast = recording.asciicast(2.0);
+    let lines = cast.lines().collect::<Vec<_>>();
+    assert_eq!(r#"{"version": 2, "width": 1, "height": 2}"#, lines[0]);
+    assert_eq!(3, lines.len());
+    assert!(lines[2].starts_with(r#"[0.500, "o", "\u001b[1;1H\u001b[0m#\u001b[0m"#));
+}
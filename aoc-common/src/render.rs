@@ -0,0 +1,159 @@
+//! Pictures of grid based puzzle states, for debugging.
+
+use std::fmt::Write;
+
+use crate::grid::Grid;
+
+/// The 8 basic terminal colours.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+impl Color {
+    /// Offset of the colour in the ANSI SGR codes, like 31 for a red foreground.
+    fn ansi_offset(self) -> u8 {
+        match self {
+            Color::Black => 0,
+            Color::Red => 1,
+            Color::Green => 2,
+            Color::Yellow => 3,
+            Color::Blue => 4,
+            Color::Magenta => 5,
+            Color::Cyan => 6,
+            Color::White => 7,
+        }
+    }
+}
+
+/// A single cell of a picture.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Glyph {
+    pub char: char,
+    pub fg: Option<Color>,
+    pub bg: Option<Color>,
+    pub bold: bool,
+    pub dim: bool,
+}
+
+impl Glyph {
+    /// `char` in the terminal's default style.
+    pub const fn plain(char: char) -> Glyph {
+        Glyph {
+            char,
+            fg: None,
+            bg: None,
+            bold: false,
+            dim: false,
+        }
+    }
+
+    pub const fn fg(self, color: Color) -> Glyph {
+        Glyph {
+            fg: Some(color),
+            ..self
+        }
+    }
+
+    pub const fn bg(self, color: Color) -> Glyph {
+        Glyph {
+            bg: Some(color),
+            ..self
+        }
+    }
+
+    pub const fn bold(self) -> Glyph {
+        Glyph { bold: true, ..self }
+    }
+
+    pub const fn dim(self) -> Glyph {
+        Glyph { dim: true, ..self }
+    }
+
+    /// The ANSI SGR codes for the style of the glyph.
+    fn sgr_codes(&self) -> Vec<u8> {
+        let mut codes = Vec::new();
+        if self.bold {
+            codes.push(1);
+        }
+        if self.dim {
+            codes.push(2);
+        }
+        if let Some(fg) = self.fg {
+            codes.push(30 + fg.ansi_offset());
+        }
+        if let Some(bg) = self.bg {
+            codes.push(40 + bg.ansi_offset());
+        }
+        codes
+    }
+}
+
+/// Puzzle states that can be drawn as a picture.
+pub trait Render {
+    fn render(&self) -> Grid<Glyph>;
+}
+
+/// The picture as lines of text, coloured with ANSI escape codes.
+pub fn ansi(picture: &Grid<Glyph>) -> String {
+    let mut out = String::new();
+    for row in picture.rows() {
+        let mut style = Vec::new();
+        for glyph in row {
+            let codes = glyph.sgr_codes();
+            if codes != style {
+                // reset first, so that no bold or background is left over
+                out.push_str("\x1b[0");
+                for code in &codes {
+                    let _ = write!(out, ";{code}");
+                }
+                out.push('m');
+            }
+            out.push(glyph.char);
+            style = codes;
+        }
+        if !style.is_empty() {
+            out.push_str("\x1b[0m");
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// A single glyph with its style, resetting the style afterwards.
+pub fn ansi_glyph(glyph: Glyph) -> String {
+    let mut out = String::from("\x1b[0");
+    for code in glyph.sgr_codes() {
+        let _ = write!(out, ";{code}");
+    }
+    let _ = write!(out, "m{}\x1b[0m", glyph.char);
+    out
+}
+
+/// The picture as lines of text, without any styling.
+pub fn plain(picture: &Grid<Glyph>) -> String {
+    picture
+        .rows()
+        .map(|row| {
+            row.iter()
+                .map(|glyph| glyph.char)
+                .chain(['\n'])
+                .collect::<String>()
+        })
+        .collect()
+}
+
+#[test]
+fn ansi_only_changes_style_when_needed() {
+    let red = Glyph::plain('#').fg(Color::Red);
+    let picture = Grid::from_fn(3, 1, |(x, _)| if x < 2 { red } else { Glyph::plain('.') });
+    assert_eq!("\x1b[0;31m##\x1b[0m.\n", ansi(&picture));
+    assert_eq!("##.\n", plain(&picture));
+}
@@ -0,0 +1,136 @@
+use std::any::Any;
+
+use crate::{
+    generate::Rng, grid::Grid, image::Image, parse::ParseError, playback::Recording, render::Glyph,
+    Answer,
+};
+
+/// A single day's puzzle.
+///
+/// The input is parsed once, after which both parts are solved from the same parsed data.
+pub trait Solution {
+    /// The day of the month the puzzle was released on.
+    const DAY: u8;
+
+    type Parsed;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError>;
+
+    fn part1(parsed: &Self::Parsed) -> Answer;
+
+    /// [`Answer::Unsolved`] as long as part 2 of the puzzle hasn't been solved.
+    fn part2(_parsed: &Self::Parsed) -> Answer {
+        Answer::Unsolved
+    }
+
+    /// A picture of the parsed input, for days that implement [`Render`](crate::render::Render).
+    fn render(_parsed: &Self::Parsed) -> Option<Grid<Glyph>> {
+        None
+    }
+
+    /// A recording of solving the puzzle, for days that implement
+    /// [`Animate`](crate::playback::Animate).
+    fn animate(_parsed: &Self::Parsed) -> Option<Recording> {
+        None
+    }
+
+    /// An image of the parsed input, for days that implement [`Draw`](crate::image::Draw).
+    fn draw(_parsed: &Self::Parsed) -> Option<Image> {
+        None
+    }
+
+    /// A random input that [`Solution::parse`] accepts, with the size of the input growing with
+    /// `size`. What `size` means depends on the day, like the number of lines or the width of a
+    /// grid.
+    fn generate(_size: usize, _rng: &mut Rng) -> Option<String> {
+        None
+    }
+}
+
+/// Object safe version of [`Solution`], so every day can be kept in one list.
+pub trait DynSolution {
+    fn day(&self) -> u8;
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+
+    /// Solve `part` (1 or 2) of the puzzle.
+    ///
+    /// # Panics
+    ///
+    /// If `parsed` doesn't come from [`DynSolution::parse`] of the same solution.
+    fn solve(&self, parsed: &dyn Any, part: u8) -> Answer;
+
+    /// See [`Solution::render`].
+    ///
+    /// # Panics
+    ///
+    /// If `parsed` doesn't come from [`DynSolution::parse`] of the same solution.
+    fn render(&self, parsed: &dyn Any) -> Option<Grid<Glyph>>;
+
+    /// See [`Solution::animate`].
+    ///
+    /// # Panics
+    ///
+    /// If `parsed` doesn't come from [`DynSolution::parse`] of the same solution.
+    fn animate(&self, parsed: &dyn Any) -> Option<Recording>;
+
+    /// See [`Solution::draw`].
+    ///
+    /// # Panics
+    ///
+    /// If `parsed` doesn't come from [`DynSolution::parse`] of the same solution.
+    fn draw(&self, parsed: &dyn Any) -> Option<Image>;
+
+    /// See [`Solution::generate`], with the random numbers coming from `seed`.
+    fn generate(&self, size: usize, seed: u64) -> Option<String>;
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution,
+    S::Parsed: 'static,
+{
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn solve(&self, parsed: &dyn Any, part: u8) -> Answer {
+        let parsed = downcast::<S>(parsed);
+
+        match part {
+            1 => S::part1(parsed),
+            2 => S::part2(parsed),
+            _ => panic!("there is no part {part}"),
+        }
+    }
+
+    fn render(&self, parsed: &dyn Any) -> Option<Grid<Glyph>> {
+        S::render(downcast::<S>(parsed))
+    }
+
+    fn animate(&self, parsed: &dyn Any) -> Option<Recording> {
+        S::animate(downcast::<S>(parsed))
+    }
+
+    fn draw(&self, parsed: &dyn Any) -> Option<Image> {
+        S::draw(downcast::<S>(parsed))
+    }
+
+    fn generate(&self, size: usize, seed: u64) -> Option<String> {
+        S::generate(size, &mut Rng::new(seed))
+    }
+}
+
+fn downcast<S>(parsed: &dyn Any) -> &S::Parsed
+where
+    S: Solution,
+    S::Parsed: 'static,
+{
+    parsed
+        .downcast_ref::<S::Parsed>()
+        .expect("parsed by the same solution")
+}
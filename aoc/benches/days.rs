@@ -0,0 +1,170 @@
+//! Times parsing and both parts of every day, on the real input and on a large generated input.
+//!
+//! Run with `cargo bench -p aoc`, or `cargo bench -p aoc -- day-05` for a single day. Criterion
+//! compares every run with the previous one, or with a named baseline when run with
+//! `--save-baseline <name>` and later `--baseline <name>`. Afterwards the results are printed as
+//! a markdown table.
+
+use std::{
+    env,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+    time::{Duration, SystemTime},
+};
+
+use aoc::solutions::SOLUTIONS;
+use aoc_common::{
+    input::{self, InputError},
+    Answer,
+};
+use criterion::{black_box, BenchmarkId, Criterion};
+
+const SYNTHETIC_SEED: u64 = 2023;
+
+/// Size of the generated input of `day`, which makes it about twice as big as the real input.
+/// What the size is depends on the day, like lines, grid width or layers in a map.
+fn synthetic_size(day: u8) -> usize {
+    match day {
+        // 1000 lines, which are longer than the generated ones
+        1 => 3000,
+        // 100 games, 200 cards or sequences
+        2 | 4 | 9 => 400,
+        // 140 by 140 grids
+        3 | 10 | 11 => 200,
+        // about 30 layers in every map and 10 ranges of seeds
+        5 => 60,
+        // there are only ever 4 races, so it's as big as the real input
+        6 => 4,
+        // 1000 hands
+        7 => 2000,
+        // about 750 nodes, and the generated networks don't use all of them
+        8 => 4000,
+        // 1000 rows of up to 20 springs, against only 100 generated rows
+        12 => 300,
+        // 100 patterns, which are bigger than the generated ones
+        13 => 250,
+        // a 100 by 100 grid
+        14 => 140,
+        _ => 100,
+    }
+}
+
+fn main() {
+    let started = SystemTime::now();
+    let mut criterion = Criterion::default()
+        .sample_size(20)
+        .warm_up_time(Duration::from_secs(1))
+        .measurement_time(Duration::from_secs(3))
+        .configure_from_args();
+
+    let mut ids = Vec::new();
+    for solution in SOLUTIONS {
+        let day = solution.day();
+        let group_name = format!("day-{day:02}");
+        let mut group = criterion.benchmark_group(&group_name);
+
+        let real = match input::load_from_dir(day) {
+            Ok(input) => Some(input),
+            Err(error @ InputError::Missing { .. }) => {
+                eprintln!("skipping the real input, {error}");
+                None
+            }
+            Err(error) => panic!("{error}"),
+        };
+        let synthetic = solution.generate(synthetic_size(day), SYNTHETIC_SEED);
+        let inputs = [("real", real), ("synthetic", synthetic)];
+
+        for (input_name, input) in inputs {
+            let Some(input) = input else {
+                continue;
+            };
+            let parsed = solution
+                .parse(&input)
+                .unwrap_or_else(|error| panic!("{input_name} input of day {day}: {error}"));
+
+            group.bench_function(BenchmarkId::new("parse", input_name), |b| {
+                b.iter(|| solution.parse(black_box(&input)))
+            });
+            ids.push(format!("{group_name}/parse/{input_name}"));
+
+            for part in [1, 2] {
+                if solution.solve(parsed.as_ref(), part) == Answer::Unsolved {
+                    continue;
+                }
+                let name = format!("part{part}");
+                group.bench_function(BenchmarkId::new(&name, input_name), |b| {
+                    b.iter(|| solution.solve(black_box(parsed.as_ref()), part))
+                });
+                ids.push(format!("{group_name}/{name}/{input_name}"));
+            }
+        }
+
+        group.finish();
+    }
+
+    criterion.final_summary();
+    println!(
+        "{}",
+        report::markdown(&report::criterion_dir(), &ids, started)
+    );
+}
+
+/// Markdown summary of the estimates criterion saved.
+mod report {
+    use super::*;
+
+    /// Where criterion saves its results, found the same way criterion does.
+    pub fn criterion_dir() -> PathBuf {
+        if let Some(dir) = env::var_os("CRITERION_HOME") {
+            return PathBuf::from(dir);
+        }
+        if let Some(dir) = env::var_os("CARGO_TARGET_DIR") {
+            return Path::new(&dir).join("criterion");
+        }
+        let metadata = env::var_os("CARGO").and_then(|cargo| {
+            Command::new(cargo)
+                .args(["metadata", "--format-version", "1", "--no-deps"])
+                .output()
+                .ok()
+        });
+        let target_dir = metadata.and_then(|output| {
+            let metadata: serde_json::Value = serde_json::from_slice(&output.stdout).ok()?;
+            Some(PathBuf::from(metadata["target_directory"].as_str()?))
+        });
+        target_dir
+            .unwrap_or_else(|| PathBuf::from("target"))
+            .join("criterion")
+    }
+
+    /// Table of the time per iteration of the benches in `ids`, and how much it changed compared
+    /// to the baseline. Benches that didn't run since `started`, because they were filtered out,
+    /// are left out.
+    pub fn markdown(dir: &Path, ids: &[String], started: SystemTime) -> String {
+        let mut out = String::from("| bench | ms/op | change |\n|---|---:|---:|\n");
+        for id in ids {
+            let Some(mean) = mean_estimate(&dir.join(id).join("new"), started) else {
+                continue;
+            };
+            let change = match mean_estimate(&dir.join(id).join("change"), started) {
+                Some(change) => format!("{:+.1}%", change * 100.0),
+                None => "-".to_string(),
+            };
+            let _ = writeln!(out, "| {id} | {:.4} | {change} |", mean / 1e6);
+        }
+        out
+    }
+
+    /// The mean in the `estimates.json` in `dir`, if it was written since `started`.
+    fn mean_estimate(dir: &Path, started: SystemTime) -> Option<f64> {
+        let path = dir.join("estimates.json");
+        let modified = fs::metadata(&path).and_then(|metadata| metadata.modified());
+        if modified.ok()? < started {
+            return None;
+        }
+        let estimates: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(path).ok()?).ok()?;
+        estimates["mean"]["point_estimate"].as_f64()
+    }
+}
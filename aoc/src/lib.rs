@@ -0,0 +1,3 @@
+//! The solutions of every day, shared by the runner and the benches.
+
+pub mod solutions;
@@ -0,0 +1,409 @@
+use std::{
+    any::Any,
+    fs,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use anyhow::Context;
+use aoc::solutions;
+use aoc_common::{
+    input::{self, InputError},
+    known_answers::KnownAnswers,
+    render, Answer, DynSolution,
+};
+use clap::{ArgGroup, Args, Parser, Subcommand};
+
+mod player;
+mod table;
+
+#[derive(Debug, Parser)]
+#[command(about = "Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Run the solutions of one or all days
+    Run(RunArgs),
+    /// Play back the simulation of a day step by step
+    #[command(after_help = format!("Keys: {}", player::KEYS))]
+    Play(PlayArgs),
+    /// Draw the parsed input of a day to an SVG or PNG image
+    Render(RenderArgs),
+    /// Generate a random puzzle input for a day
+    Gen(GenArgs),
+    /// Check the answers of every day against the known answers, remembering the ones that
+    /// aren't known yet
+    Verify(VerifyArgs),
+    /// Look up where a seed ends up, or where a location comes from, in the almanac of day 5
+    Query(QueryArgs),
+}
+
+#[derive(Debug, Args)]
+struct RunArgs {
+    /// Day to run
+    #[arg(short, long, required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u8>,
+
+    /// Only run this part, instead of both
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Puzzle input to use, instead of stdin or `$AOC_INPUT_DIR/day-XX.txt`
+    #[arg(short, long, requires = "day")]
+    input: Option<PathBuf>,
+
+    /// Run every day
+    #[arg(short, long)]
+    all: bool,
+
+    /// Print a picture of the parsed input, for the days that have one
+    #[arg(long)]
+    visualize: bool,
+}
+
+#[derive(Debug, Args)]
+struct PlayArgs {
+    /// Day to play
+    #[arg(short, long)]
+    day: u8,
+
+    /// Puzzle input to use, instead of stdin or `$AOC_INPUT_DIR/day-XX.txt`
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    /// Frames per second, from 0.01 to 1000
+    #[arg(long, default_value_t = 10.0, value_parser = parse_fps)]
+    fps: f64,
+
+    /// Write the frames to this asciicast file, instead of playing them
+    #[arg(long)]
+    export: Option<PathBuf>,
+}
+
+#[derive(Debug, Args)]
+struct RenderArgs {
+    /// Day to draw
+    #[arg(short, long)]
+    day: u8,
+
+    /// Puzzle input to use, instead of stdin or `$AOC_INPUT_DIR/day-XX.txt`
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    /// Image file to write, its format is picked by the `.svg` or `.png` extension
+    #[arg(short, long)]
+    out: PathBuf,
+}
+
+#[derive(Debug, Args)]
+struct GenArgs {
+    /// Day to generate an input for
+    #[arg(short, long)]
+    day: u8,
+
+    /// How big the input should be, like the number of lines or the width of a grid, depending
+    /// on the day
+    #[arg(short, long, default_value_t = 100)]
+    size: usize,
+
+    /// Seed of the random numbers, the same seed always gives the same input
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// File to write the input to, instead of stdout
+    #[arg(short, long)]
+    out: Option<PathBuf>,
+}
+
+#[derive(Debug, Args)]
+struct VerifyArgs {
+    /// Only check this day, instead of every day
+    #[arg(short, long)]
+    day: Option<u8>,
+}
+
+#[derive(Debug, Args)]
+#[command(group(ArgGroup::new("lookup").required(true)))]
+struct QueryArgs {
+    /// Day to look things up in, only day 5 can be queried
+    #[arg(short, long)]
+    day: u8,
+
+    /// Puzzle input to use, instead of stdin or `$AOC_INPUT_DIR/day-XX.txt`
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    /// Follow this seed through every map to its location
+    #[arg(long, group = "lookup")]
+    seed: Option<u64>,
+
+    /// Find every seed that ends up at this location
+    #[arg(long, group = "lookup")]
+    location: Option<u64>,
+
+    /// Follow a number of one category to another, like `--convert humidity location 78`
+    #[arg(long, group = "lookup", num_args = 3, value_names = ["FROM", "TO", "NUMBER"])]
+    convert: Option<Vec<String>>,
+}
+
+/// The result of running a single part of a day.
+#[derive(Debug)]
+pub struct PartResult {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+    /// Time spent parsing the input, shared by both parts
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run(args) => run(args),
+        Command::Play(args) => play(args),
+        Command::Render(args) => render(args),
+        Command::Gen(args) => generate(args),
+        Command::Verify(args) => verify(args),
+        Command::Query(args) => query(args),
+    }
+}
+
+fn run(args: RunArgs) -> anyhow::Result<()> {
+    let solutions = match args.day {
+        Some(day) => {
+            vec![solutions::get(day).with_context(|| format!("no solution for day {day}"))?]
+        }
+        None => solutions::SOLUTIONS.to_vec(),
+    };
+
+    let mut results = Vec::new();
+    for solution in solutions {
+        let day = solution.day();
+        let input = if args.all {
+            match input::load_from_dir(day) {
+                Ok(input) => input,
+                Err(error @ InputError::Missing { .. }) => {
+                    eprintln!("skipping day {day}: {error}");
+                    continue;
+                }
+                Err(error) => return Err(error.into()),
+            }
+        } else {
+            input::load(day, args.input.as_deref())?
+        };
+
+        let start = Instant::now();
+        let parsed = parse(solution, &input)?;
+        let parse_time = start.elapsed();
+
+        if args.visualize {
+            match solution.render(parsed.as_ref()) {
+                Some(picture) => println!("day {day}:\n{}", render::ansi(&picture)),
+                None => eprintln!("day {day} has no visualization"),
+            }
+        }
+
+        let parts = match args.part {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        };
+        for part in parts {
+            let start = Instant::now();
+            let answer = solution.solve(parsed.as_ref(), part);
+            let solve_time = start.elapsed();
+
+            results.push(PartResult {
+                day,
+                part,
+                answer,
+                parse_time,
+                solve_time,
+            });
+        }
+    }
+
+    table::print(&results);
+    Ok(())
+}
+
+/// Parse `input`, reporting where it went wrong if it can't be.
+fn parse(solution: &dyn DynSolution, input: &str) -> anyhow::Result<Box<dyn Any>> {
+    solution.parse(input).map_err(|error| {
+        anyhow::anyhow!("{}", error.report(input))
+            .context(format!("parsing input of day {}", solution.day()))
+    })
+}
+
+fn play(args: PlayArgs) -> anyhow::Result<()> {
+    let day = args.day;
+    let solution = solutions::get(day).with_context(|| format!("no solution for day {day}"))?;
+
+    let input = input::load(day, args.input.as_deref())?;
+    let parsed = parse(solution, &input)?;
+    let recording = solution
+        .animate(parsed.as_ref())
+        .with_context(|| format!("day {day} has no simulation to play"))?;
+
+    match args.export {
+        Some(path) => fs::write(&path, recording.asciicast(args.fps))
+            .with_context(|| format!("writing {}", path.display()))?,
+        None => player::play(&recording, args.fps)?,
+    }
+    Ok(())
+}
+
+/// Frame rates slow or fast enough that the time between frames can't be kept track of are
+/// rejected.
+fn parse_fps(value: &str) -> Result<f64, String> {
+    let fps = value.parse::<f64>().map_err(|error| error.to_string())?;
+    if !(0.01..=1000.0).contains(&fps) {
+        return Err(format!("{value} is not between 0.01 and 1000"));
+    }
+    Ok(fps)
+}
+
+fn render(args: RenderArgs) -> anyhow::Result<()> {
+    let day = args.day;
+    let solution = solutions::get(day).with_context(|| format!("no solution for day {day}"))?;
+
+    let input = input::load(day, args.input.as_deref())?;
+    let parsed = parse(solution, &input)?;
+    let image = solution
+        .draw(parsed.as_ref())
+        .with_context(|| format!("day {day} has no image to draw"))?;
+
+    let contents = match args.out.extension().and_then(|ext| ext.to_str()) {
+        Some("svg") => image.svg().into_bytes(),
+        #[cfg(feature = "png")]
+        Some("png") => image.png().map_err(anyhow::Error::msg)?,
+        #[cfg(not(feature = "png"))]
+        Some("png") => anyhow::bail!("the runner is built without PNG support"),
+        _ => anyhow::bail!("{} should end in .svg or .png", args.out.display()),
+    };
+    fs::write(&args.out, contents).with_context(|| format!("writing {}", args.out.display()))
+}
+
+fn generate(args: GenArgs) -> anyhow::Result<()> {
+    let day = args.day;
+    let solution = solutions::get(day).with_context(|| format!("no solution for day {day}"))?;
+    let input = solution
+        .generate(args.size, args.seed)
+        .with_context(|| format!("day {day} has no input generator"))?;
+
+    match args.out {
+        Some(path) => {
+            fs::write(&path, input).with_context(|| format!("writing {}", path.display()))?
+        }
+        None => print!("{input}"),
+    }
+    Ok(())
+}
+
+fn verify(args: VerifyArgs) -> anyhow::Result<()> {
+    let solutions = match args.day {
+        Some(day) => {
+            vec![solutions::get(day).with_context(|| format!("no solution for day {day}"))?]
+        }
+        None => solutions::SOLUTIONS.to_vec(),
+    };
+    let path =
+        KnownAnswers::path().with_context(|| format!("{} is not set", input::INPUT_DIR_VAR))?;
+    let mut known = KnownAnswers::load(&path)?;
+
+    let mut rows = Vec::new();
+    let mut failed = 0;
+    let mut learned = 0;
+    for solution in solutions {
+        let day = solution.day();
+        let input = match input::load_from_dir(day) {
+            Ok(input) => input,
+            Err(error @ InputError::Missing { .. }) => {
+                eprintln!("skipping day {day}: {error}");
+                continue;
+            }
+            Err(error) => return Err(error.into()),
+        };
+        let parsed = parse(solution, &input)?;
+
+        for part in [1, 2] {
+            let answer = solution.solve(parsed.as_ref(), part);
+            let expected = known.get(day, part, &input).map(str::to_string);
+            let status = match (&answer, expected) {
+                (Answer::Unsolved | Answer::Impossible, None) => "-".to_string(),
+                (_, Some(expected)) if expected == answer.to_string() => "pass".to_string(),
+                (_, Some(expected)) => {
+                    failed += 1;
+                    format!("FAIL, expected {expected}")
+                }
+                (_, None) => {
+                    known.insert(day, part, &input, answer.to_string());
+                    learned += 1;
+                    "unknown, remembered".to_string()
+                }
+            };
+            rows.push([
+                day.to_string(),
+                part.to_string(),
+                answer.to_string(),
+                status,
+            ]);
+        }
+    }
+
+    table::print_rows(["day", "part", "answer", "status"], &rows);
+    if learned > 0 {
+        known.save(&path)?;
+        eprintln!("remembered {learned} new answers in {}", path.display());
+    }
+    anyhow::ensure!(
+        failed == 0,
+        "{failed} answers don't match the known answers"
+    );
+    Ok(())
+}
+
+fn query(args: QueryArgs) -> anyhow::Result<()> {
+    let day = args.day;
+    anyhow::ensure!(day == 5, "day {day} can't be queried, only day 5 can");
+    let solution = solutions::get(day).with_context(|| format!("no solution for day {day}"))?;
+
+    let input = input::load(day, args.input.as_deref())?;
+    let parsed = parse(solution, &input)?;
+    let almanac = parsed
+        .downcast_ref::<day_05::Almanac>()
+        .expect("parsed by day 5");
+
+    if let Some(seed) = args.seed {
+        let chain = almanac
+            .chain(seed)
+            .iter()
+            .map(|(category, number)| format!("{category} {number}"))
+            .collect::<Vec<_>>();
+        println!("{}", chain.join(" -> "));
+    }
+    if let Some(location) = args.location {
+        let seeds = almanac.seeds_at(location);
+        if seeds.is_empty() {
+            println!("no seed ends up at location {location}");
+        } else {
+            let seeds = seeds.iter().map(u64::to_string).collect::<Vec<_>>();
+            println!("seeds at location {location}: {}", seeds.join(", "));
+        }
+    }
+    if let Some([from, to, number]) = args.convert.as_deref() {
+        let number = number
+            .parse()
+            .with_context(|| format!("{number} is not a number"))?;
+        let converted = almanac
+            .convert(from, to, number)
+            .with_context(|| format!("the almanac doesn't lead from {from} to {to}"))?;
+        println!("{from} {number} -> {to} {converted}");
+    }
+    Ok(())
+}
@@ -0,0 +1,106 @@
+//! Interactive terminal playback of a [`Recording`].
+
+use std::{
+    io::{self, Write},
+    time::{Duration, Instant},
+};
+
+use aoc_common::playback::{self, Recording};
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    execute, terminal,
+};
+
+pub const KEYS: &str = "space: pause, ←/→: step, home/end: first/last frame, 0-9: seek, q: quit";
+
+/// Play `recording` at `fps` frames per second, until the user quits.
+pub fn play(recording: &Recording, fps: f64) -> io::Result<()> {
+    let _terminal = RawTerminal::enter()?;
+    let mut out = io::stdout().lock();
+    let frame_time = Duration::from_secs_f64(1.0 / fps);
+    let last = recording.len() - 1;
+
+    let mut shown = None;
+    let mut idx = 0;
+    let mut paused = false;
+    let mut next_frame = Instant::now() + frame_time;
+    loop {
+        // only the changes are needed when going to the next frame
+        let screen = match shown {
+            Some(shown) if shown + 1 == idx => playback::ansi_changes(recording.changes(idx)),
+            Some(shown) if shown == idx => String::new(),
+            _ => format!("\x1b[2J{}", playback::ansi_screen(&recording.frame(idx))),
+        };
+        let status = format!(
+            "frame {idx}/{last} ({}){}  [{KEYS}]",
+            recording.label(idx),
+            if paused { ", paused" } else { "" },
+        );
+        write!(
+            out,
+            "{screen}{}",
+            playback::status_line(recording.height(), &status)
+        )?;
+        out.flush()?;
+        shown = Some(idx);
+
+        let timeout = next_frame.saturating_duration_since(Instant::now());
+        if !event::poll(timeout)? {
+            if !paused {
+                idx = (idx + 1).min(last);
+                paused = idx == last;
+            }
+            next_frame = Instant::now() + frame_time;
+            continue;
+        }
+
+        let Event::Key(KeyEvent {
+            code,
+            modifiers,
+            kind: KeyEventKind::Press,
+            ..
+        }) = event::read()?
+        else {
+            continue;
+        };
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+            KeyCode::Char('c') if modifiers.contains(KeyModifiers::CONTROL) => return Ok(()),
+            KeyCode::Char(' ') => paused = !paused,
+            KeyCode::Right | KeyCode::Char('l') => {
+                paused = true;
+                idx = (idx + 1).min(last);
+            }
+            KeyCode::Left | KeyCode::Char('h') => {
+                paused = true;
+                idx = idx.saturating_sub(1);
+            }
+            KeyCode::Home | KeyCode::Char('g') => idx = 0,
+            KeyCode::End | KeyCode::Char('G') => idx = last,
+            KeyCode::Char(digit @ '0'..='9') => {
+                let tenths = digit.to_digit(10).unwrap_or_default() as usize;
+                idx = last * tenths / 10;
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Raw mode on the alternate screen, restored when dropped.
+struct RawTerminal;
+
+impl RawTerminal {
+    fn enter() -> io::Result<RawTerminal> {
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), terminal::EnterAlternateScreen, cursor::Hide)?;
+        Ok(RawTerminal)
+    }
+}
+
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
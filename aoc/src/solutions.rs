@@ -0,0 +1,70 @@
+use aoc_common::DynSolution;
+
+pub const SOLUTIONS: &[&dyn DynSolution] = &[
+    &day_01::Day01,
+    &day_02::Day02,
+    &day_03::Day03,
+    &day_04::Day04,
+    &day_05::Day05,
+    &day_06::Day06,
+    &day_07::Day07,
+    &day_08::Day08,
+    &day_09::Day09,
+    &day_10::Day10,
+    &day_11::Day11,
+    &day_12::Day12,
+    &day_13::Day13,
+    &day_14::Day14,
+];
+
+pub fn get(day: u8) -> Option<&'static dyn DynSolution> {
+    SOLUTIONS
+        .iter()
+        .find(|solution| solution.day() == day)
+        .copied()
+}
+
+#[test]
+fn sorted_by_day() {
+    let days = SOLUTIONS.iter().map(|solution| solution.day());
+    assert!(days.clone().zip(days.skip(1)).all(|(a, b)| a < b));
+}
+
+#[test]
+fn generated_inputs_parse() {
+    for solution in SOLUTIONS {
+        let day = solution.day();
+        // the smallest sizes, and the size `aoc gen` uses by default
+        for size in [0, 1, 100] {
+            let Some(input) = solution.generate(size, 1) else {
+                continue;
+            };
+            let parsed = solution
+                .parse(&input)
+                .unwrap_or_else(|error| panic!("day {day}, size {size}: {error}"));
+            for part in [1, 2] {
+                let answer = solution.solve(parsed.as_ref(), part);
+                assert_ne!(
+                    aoc_common::Answer::Impossible,
+                    answer,
+                    "day {day} part {part}, size {size}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn truncated_inputs_dont_panic() {
+    for solution in SOLUTIONS {
+        let input = solution.generate(2, 0).unwrap_or_default();
+        let ends = (0..input.len()).filter(|&end| input.is_char_boundary(end));
+        for end in ends {
+            if let Ok(parsed) = solution.parse(&input[..end]) {
+                for part in [1, 2] {
+                    solution.solve(parsed.as_ref(), part);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use crate::PartResult;
+
+/// Print the results as a table, with every column right aligned.
+pub fn print(results: &[PartResult]) {
+    let rows = results
+        .iter()
+        .map(|result| {
+            [
+                result.day.to_string(),
+                result.part.to_string(),
+                result.answer.to_string(),
+                format!("{:.2?}", result.parse_time),
+                format!("{:.2?}", result.solve_time),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    print_rows(["day", "part", "answer", "parse", "solve"], &rows);
+}
+
+/// Print `rows` below `header`, with every column right aligned.
+pub fn print_rows<const N: usize>(header: [&str; N], rows: &[[String; N]]) {
+    let mut widths = header.map(str::len);
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    print_row(&header, &widths);
+    println!(
+        "{}",
+        widths
+            .iter()
+            .map(|&width| "-".repeat(width + 2))
+            .collect::<Vec<_>>()
+            .join("+")
+    );
+    for row in rows {
+        print_row(row, &widths);
+    }
+}
+
+fn print_row(row: &[impl AsRef<str>], widths: &[usize]) {
+    let cells = row
+        .iter()
+        .zip(widths)
+        .map(|(cell, width)| format!(" {:>width$} ", cell.as_ref()))
+        .collect::<Vec<_>>();
+    println!("{}", cells.join("|").trim_end());
+}
@@ -0,0 +1,23 @@
+use aoc_common::generate::Rng;
+
+const DIGIT_WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// `size` lines of letters, digits and spelled out digits, with at least one digit on every line.
+pub(crate) fn input(size: usize, rng: &mut Rng) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        let digit_at = rng.below(8);
+        for idx in 0..rng.between(digit_at + 1, 12) {
+            match rng.below(4) {
+                _ if idx == digit_at => out.push(char::from(b'1' + rng.below(9) as u8)),
+                0 => out.push_str(rng.pick::<&str>(&DIGIT_WORDS)),
+                1 => out.push(char::from(b'0' + rng.below(10) as u8)),
+                _ => out.push(char::from(b'a' + rng.below(26) as u8)),
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
@@ -0,0 +1,35 @@
+use aoc_common::{generate::Rng, parse::ParseError, Answer, Solution};
+
+mod generate;
+mod part1;
+mod part2;
+
+const RADIX: u32 = 10;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::process(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::process(parsed).into()
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate::input(size, rng))
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    Ok(input.lines().map(String::from).collect())
+}
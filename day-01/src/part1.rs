@@ -0,0 +1,37 @@
+use crate::RADIX;
+
+pub(crate) fn process(lines: &[String]) -> u32 {
+    lines
+        .iter()
+        .map(|line| {
+            let mut digits = line.chars().filter_map(|digit| digit.to_digit(RADIX));
+            let first = digits.next().unwrap_or_default();
+            let last = digits.next_back().unwrap_or(first);
+            RADIX * first + last
+        })
+        .sum::<u32>()
+}
+
+#[test]
+fn example() {
+    let input = "1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+    let expected = 142;
+
+    let parsed = crate::parse(input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(expected, output);
+}
+
+#[test]
+fn real_input() {
+    let Some((input, expected)) = aoc_common::known_answers::real_answer(1, 1) else {
+        return;
+    };
+
+    let parsed = crate::parse(&input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(expected, aoc_common::Answer::from(output).to_string());
+}
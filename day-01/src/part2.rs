@@ -1,11 +1,12 @@
-const RADIX: u32 = 10;
+use crate::RADIX;
+
 const DIGIT_WORDS: [&str; RADIX as usize] = [
     "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
 ];
 
-fn process(input: &str) -> String {
-    input
-        .lines()
+pub(crate) fn process(lines: &[String]) -> u32 {
+    lines
+        .iter()
         .map(|line| {
             let mut digits = line.char_indices().filter_map(|(idx, char)| {
                 char.to_digit(RADIX).or_else(|| {
@@ -16,17 +17,10 @@ fn process(input: &str) -> String {
             });
 
             let first = digits.next().unwrap_or_default();
-            let last = digits.last().unwrap_or(first);
+            let last = digits.next_back().unwrap_or(first);
             RADIX * first + last
         })
         .sum::<u32>()
-        .to_string()
-}
-
-fn main() {
-    let input = include_str!("input.txt");
-    let output = process(input);
-    println!("{output}");
 }
 
 #[test]
@@ -38,13 +32,20 @@ xtwone3four
 4nineeightseven2
 zoneight234
 7pqrstsixteen";
-    let expected = "281";
-    assert_eq!(expected, process(input));
+    let expected = 281;
+
+    let parsed = crate::parse(input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(expected, output);
 }
 
 #[test]
 fn real_input() {
-    let input = include_str!("input.txt");
-    let expected = "55413";
-    assert_eq!(expected, process(input));
+    let Some((input, expected)) = aoc_common::known_answers::real_answer(1, 2) else {
+        return;
+    };
+
+    let parsed = crate::parse(&input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(expected, aoc_common::Answer::from(output).to_string());
 }
@@ -0,0 +1,21 @@
+use aoc_common::generate::Rng;
+
+/// `size` games of up to 6 grabs, each with up to 20 cubes of every colour.
+pub(crate) fn input(size: usize, rng: &mut Rng) -> String {
+    let mut out = String::new();
+    for id in 1..=size {
+        let grabs = (0..rng.between(1, 6))
+            .map(|_| {
+                let mut colors = ["red", "green", "blue"];
+                rng.shuffle(&mut colors);
+                colors[..rng.between(1, 3) as usize]
+                    .iter()
+                    .map(|color| format!("{} {color}", rng.between(1, 20)))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect::<Vec<_>>();
+        out.push_str(&format!("Game {id}: {}\n", grabs.join("; ")));
+    }
+    out
+}
@@ -0,0 +1,104 @@
+use std::str::FromStr;
+
+use aoc_common::{generate::Rng, parse::ParseError, Answer, Solution};
+
+mod generate;
+mod part1;
+mod part2;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Parsed = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::process(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::process(parsed).into()
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate::input(size, rng))
+    }
+}
+
+/// Every grab of every round in a game.
+#[derive(Debug)]
+pub struct Game(Vec<Grab>);
+
+#[derive(Debug)]
+struct Grab {
+    num: u32,
+    color: Color,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+impl FromStr for Color {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "red" => Ok(Color::Red),
+            "green" => Ok(Color::Green),
+            "blue" => Ok(Color::Blue),
+            _ => Err(format!("invalid color {s}")),
+        }
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let (_, game) = line
+                .split_once(": ")
+                .ok_or_else(|| ParseError::expected(input, line, "'Game x: '"))?;
+            let grabs = game
+                .split("; ")
+                .flat_map(|round| round.split(", "))
+                .map(|grab| {
+                    let (num, color) = grab
+                        .split_once(' ')
+                        .ok_or_else(|| ParseError::expected(input, grab, "'<num> <color>'"))?;
+                    let num = num
+                        .parse::<u32>()
+                        .map_err(|_| ParseError::expected(input, num, "a number"))?;
+                    let color = color
+                        .parse::<Color>()
+                        .map_err(|_| ParseError::expected(input, color, "red, green or blue"))?;
+                    Ok(Grab { num, color })
+                })
+                .collect::<Result<_, _>>()?;
+            Ok(Game(grabs))
+        })
+        .collect()
+}
+
+#[test]
+fn parse_bad_color() {
+    let input = "Game 1: 3 blue, 4 red
+Game 2: 1 blue, 2 purple";
+    let expected = ParseError::Unexpected {
+        location: aoc_common::parse::Location {
+            line: 2,
+            column: 19,
+        },
+        found: "purple".to_string(),
+        expected: "red, green or blue".to_string(),
+    };
+    assert_eq!(expected, parse(input).unwrap_err());
+}
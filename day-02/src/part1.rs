@@ -0,0 +1,45 @@
+use crate::{Color, Game};
+
+pub(crate) fn process(games: &[Game]) -> usize {
+    let cube_counts = maplit::btreemap! {
+        Color::Red => 12,
+        Color::Green => 13,
+        Color::Blue => 14,
+    };
+
+    games
+        .iter()
+        .map(|game| {
+            game.0
+                .iter()
+                .all(|grab| grab.num <= cube_counts[&grab.color])
+        })
+        .enumerate()
+        .filter_map(|(idx, valid)| valid.then_some(idx + 1))
+        .sum::<usize>()
+}
+
+#[test]
+fn example() {
+    let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+    let expected = 8;
+
+    let parsed = crate::parse(input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(expected, output);
+}
+
+#[test]
+fn real_input() {
+    let Some((input, expected)) = aoc_common::known_answers::real_answer(2, 1) else {
+        return;
+    };
+
+    let parsed = crate::parse(&input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(expected, aoc_common::Answer::from(output).to_string());
+}
@@ -0,0 +1,51 @@
+use itertools::Itertools;
+
+use crate::Game;
+
+/// Sum of the powers of the fewest cubes every game could be played with, `None` if it doesn't
+/// fit in a `u64`.
+pub(crate) fn process(games: &[Game]) -> Option<u64> {
+    games.iter().try_fold(0u64, |sum, game| {
+        let power = game
+            .0
+            .iter()
+            .map(|grab| (grab.color, grab.num))
+            .into_group_map()
+            .into_values()
+            .map(|nums| nums.into_iter().max().unwrap_or_default())
+            .try_fold(1u64, |power, num| power.checked_mul(num.into()))?;
+        sum.checked_add(power)
+    })
+}
+
+#[test]
+fn example() {
+    let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+    let expected = 2286;
+
+    let parsed = crate::parse(input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(Some(expected), output);
+}
+
+#[test]
+fn real_input() {
+    let Some((input, expected)) = aoc_common::known_answers::real_answer(2, 2) else {
+        return;
+    };
+
+    let parsed = crate::parse(&input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(expected, aoc_common::Answer::from(output).to_string());
+}
+
+#[test]
+fn power_too_large() {
+    let input = "Game 1: 4000000000 red, 4000000000 green, 4000000000 blue";
+    let parsed = crate::parse(input).unwrap();
+    assert_eq!(None, process(&parsed));
+}
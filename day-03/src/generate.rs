@@ -0,0 +1,29 @@
+use aoc_common::generate::Rng;
+
+const SYMBOLS: [char; 10] = ['*', '#', '+', '$', '/', '@', '=', '%', '&', '-'];
+
+/// A `size` by `size` schematic, with numbers of up to 3 digits and a symbol every 10 cells or so.
+pub(crate) fn input(size: usize, rng: &mut Rng) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        let mut row = String::new();
+        while row.len() < size {
+            match rng.below(10) {
+                0 => row.push(*rng.pick(&SYMBOLS)),
+                1 | 2 => {
+                    let digits = rng.between(1, 3).min((size - row.len()) as u64);
+                    let number =
+                        rng.between(10u64.pow(digits as u32 - 1), 10u64.pow(digits as u32) - 1);
+                    row.push_str(&number.to_string());
+                    if row.len() < size {
+                        row.push('.');
+                    }
+                }
+                _ => row.push('.'),
+            }
+        }
+        out.push_str(&row);
+        out.push('\n');
+    }
+    out
+}
@@ -0,0 +1,111 @@
+use aoc_common::{
+    generate::Rng,
+    grid::{self, Grid},
+    parse::ParseError,
+    Answer, Solution,
+};
+
+mod generate;
+mod part1;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Parsed = Schematic;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::process(parsed).into()
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate::input(size, rng))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Number {
+    value: u32,
+    y: usize,
+    first_digit_x: usize,
+    last_digit_x: usize,
+}
+
+impl Number {
+    fn coords(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (self.first_digit_x..=self.last_digit_x).map(|x| (x, self.y))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Empty,
+    Digit(u32),
+    Symbol,
+}
+
+impl From<char> for Cell {
+    fn from(value: char) -> Self {
+        match value {
+            '.' => Cell::Empty,
+            c => c.to_digit(10).map_or(Cell::Symbol, Cell::Digit),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Schematic {
+    grid: Grid<Cell>,
+    numbers: Vec<Number>,
+}
+
+fn parse(input: &str) -> Result<Schematic, ParseError> {
+    let grid: Grid<Cell> = input.parse()?;
+
+    let mut numbers = Vec::<Number>::new();
+    for (y, row) in grid.rows().enumerate() {
+        let mut new_number = true;
+        for (x, &cell) in row.iter().enumerate() {
+            let Cell::Digit(digit) = cell else {
+                // end the number when a '.' or symbol is encountered
+                new_number = true;
+                continue;
+            };
+
+            match numbers.last_mut() {
+                Some(last_number) if !new_number => {
+                    last_number.value = last_number
+                        .value
+                        .checked_mul(10)
+                        .and_then(|value| value.checked_add(digit))
+                        .ok_or_else(|| {
+                            let char = char::from_digit(digit, 10).unwrap_or_default();
+                            grid::invalid_cell((x, y), char, "the number is too large")
+                        })?;
+                    last_number.last_digit_x = x;
+                }
+                _ => numbers.push(Number {
+                    value: digit,
+                    y,
+                    first_digit_x: x,
+                    last_digit_x: x,
+                }),
+            }
+            new_number = false;
+        }
+    }
+
+    Ok(Schematic { grid, numbers })
+}
+
+#[test]
+fn parse_number_too_large() {
+    let input = "..12345678901*";
+    let expected = grid::invalid_cell((12, 0), '1', "the number is too large");
+    assert_eq!(expected, parse(input).unwrap_err());
+}
@@ -0,0 +1,86 @@
+use crate::{Cell, Schematic};
+
+pub(crate) fn process(input: &Schematic) -> u64 {
+    let Schematic { grid, numbers } = input;
+
+    let valid_numbers = numbers.iter().filter(|number| {
+        number.coords().any(|coords| {
+            grid.neighbours8(coords)
+                .any(|neighbour| grid[neighbour] == Cell::Symbol)
+        })
+    });
+
+    valid_numbers
+        .map(|number| u64::from(number.value))
+        .sum::<u64>()
+}
+
+#[test]
+fn example() {
+    let input = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+    let expected = 4361;
+
+    let parsed = crate::parse(input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(expected, output);
+}
+
+#[test]
+fn example2() {
+    let input = "467..114..
+...*......
+..35..633.
+......#111
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+    let expected = 4472;
+
+    let parsed = crate::parse(input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(expected, output);
+}
+
+#[test]
+fn example3() {
+    let input = "467..114..
+...*......
+..35..633.
+......#...
+..........
+617*111...
+..........
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+    let expected = 4472;
+
+    let parsed = crate::parse(input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(expected, output);
+}
+
+#[test]
+fn real_input() {
+    let Some((input, expected)) = aoc_common::known_answers::real_answer(3, 1) else {
+        return;
+    };
+
+    let parsed = crate::parse(&input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(expected, aoc_common::Answer::from(output).to_string());
+}
@@ -0,0 +1,48 @@
+use aoc_common::generate::Rng;
+
+const WINNING_NUMBERS: usize = 10;
+const MY_NUMBERS: usize = 25;
+
+/// Cards with more copies than this don't win any more cards, so that the number of copies can't
+/// grow exponentially.
+const MAX_WINNING_COPIES: u64 = 10_000;
+
+/// `size` cards with numbers up to 99, like the real ones.
+///
+/// No card wins more cards than there are left after it.
+pub(crate) fn input(size: usize, rng: &mut Rng) -> String {
+    let size = size.max(1);
+    let mut out = String::new();
+    let mut copies = vec![1; size];
+    for idx in 0..size {
+        let mut numbers = (1..100).collect::<Vec<_>>();
+        rng.shuffle(&mut numbers);
+        let (winning, others) = numbers.split_at(WINNING_NUMBERS);
+
+        let max_matching = if copies[idx] > MAX_WINNING_COPIES {
+            0
+        } else {
+            WINNING_NUMBERS.min(size - 1 - idx)
+        };
+        let matching = rng.below(max_matching as u64 + 1) as usize;
+        for won in idx + 1..=idx + matching {
+            copies[won] += copies[idx];
+        }
+        let mut mine = [&winning[..matching], &others[..MY_NUMBERS - matching]].concat();
+        rng.shuffle(&mut mine);
+
+        let numbers = |numbers: &[u32]| {
+            numbers
+                .iter()
+                .map(|number| format!(" {number:>2}"))
+                .collect::<String>()
+        };
+        out.push_str(&format!(
+            "Card {:>3}:{} |{}\n",
+            idx + 1,
+            numbers(winning),
+            numbers(&mine)
+        ));
+    }
+    out
+}
@@ -0,0 +1,79 @@
+use std::collections::HashSet;
+
+use aoc_common::{
+    generate::Rng,
+    parse::{finish, lines, number_set, ParseError},
+    Answer, Solution,
+};
+use nom::{
+    character::complete::{space1, u32},
+    sequence::{preceded, separated_pair, tuple},
+    Parser,
+};
+use nom_supreme::{error::ErrorTree, tag::complete::tag, ParserExt};
+
+mod generate;
+mod part1;
+mod part2;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Parsed = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::process(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::process(parsed).into()
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate::input(size, rng))
+    }
+}
+
+#[derive(Debug)]
+pub struct Card {
+    winning_numbers: HashSet<u32>,
+    my_numbers: HashSet<u32>,
+}
+
+impl Card {
+    fn n_winning_numbers(&self) -> u32 {
+        self.winning_numbers.intersection(&self.my_numbers).count() as u32
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Card>, ParseError> {
+    let header = tuple((tag("Card"), space1, u32, tag(":"))).context("card header");
+    let card = preceded(
+        header,
+        separated_pair(
+            number_set.context("winning numbers"),
+            tag(" |"),
+            number_set.context("my numbers"),
+        ),
+    )
+    .map(|(winning_numbers, my_numbers)| Card {
+        winning_numbers,
+        my_numbers,
+    });
+    let mut parser = lines(card.context("card"));
+    finish::<_, ErrorTree<_>>(input, parser(input))
+}
+
+#[test]
+fn parse_missing_separator() {
+    let input = "Card 1: 41 48 | 83 86
+Card 2: 13 32 61";
+    let error = parse(input).unwrap_err();
+    assert_eq!(2, error.location().line);
+}
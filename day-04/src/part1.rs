@@ -0,0 +1,50 @@
+use crate::Card;
+
+/// Total points of the cards, `None` if they don't fit in a `u64`.
+pub(crate) fn process(cards: &[Card]) -> Option<u64> {
+    cards.iter().try_fold(0u64, |sum, card| {
+        let n_winning_numbers = card.n_winning_numbers();
+        let points = if n_winning_numbers > 0 {
+            2u64.checked_pow(n_winning_numbers - 1)?
+        } else {
+            0
+        };
+        sum.checked_add(points)
+    })
+}
+
+#[test]
+fn example() {
+    let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+    let expected = 13;
+
+    let parsed = crate::parse(input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(Some(expected), output);
+}
+
+#[test]
+fn copies_past_the_end() {
+    // the last card wins a copy of a card that isn't there, which doesn't change its points
+    let input = "Card 1: 41 48 | 48 1
+Card 2: 13 32 | 32 1";
+
+    let parsed = crate::parse(input).unwrap();
+    assert_eq!(Some(2), process(&parsed));
+}
+
+#[test]
+fn real_input() {
+    let Some((input, expected)) = aoc_common::known_answers::real_answer(4, 1) else {
+        return;
+    };
+
+    let parsed = crate::parse(&input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(expected, aoc_common::Answer::from(output).to_string());
+}
@@ -0,0 +1,57 @@
+use crate::Card;
+
+/// Number of cards after winning copies of cards, `None` if it doesn't fit in a `u64`. Copies of
+/// cards past the end of the table aren't won.
+pub(crate) fn process(cards: &[Card]) -> Option<u64> {
+    let num_matching = cards
+        .iter()
+        .map(Card::n_winning_numbers)
+        .collect::<Vec<_>>();
+    let mut multipliers = vec![1u64; num_matching.len()];
+    let len = multipliers.len();
+    for i in 0..len {
+        let this_multiplier = multipliers[i];
+        let won = i + 1..(i + 1 + num_matching[i] as usize).min(len);
+        for m in &mut multipliers[won] {
+            *m = m.checked_add(this_multiplier)?;
+        }
+    }
+    multipliers
+        .into_iter()
+        .try_fold(0u64, |sum, multiplier| sum.checked_add(multiplier))
+}
+
+#[test]
+fn example() {
+    let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+    let expected = 30;
+
+    let parsed = crate::parse(input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(Some(expected), output);
+}
+
+#[test]
+fn copies_past_the_end() {
+    let input = "Card 1: 41 48 | 48 1
+Card 2: 13 32 | 32 1";
+
+    let parsed = crate::parse(input).unwrap();
+    assert_eq!(Some(3), process(&parsed));
+}
+
+#[test]
+fn real_input() {
+    let Some((input, expected)) = aoc_common::known_answers::real_answer(4, 2) else {
+        return;
+    };
+
+    let parsed = crate::parse(&input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(expected, aoc_common::Answer::from(output).to_string());
+}
@@ -0,0 +1,52 @@
+use aoc_common::generate::Rng;
+
+pub(crate) const CATEGORIES: [&str; 8] = [
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
+/// An almanac with `size` layers in every map, and `size / 5` ranges of seeds.
+pub(crate) fn input(size: usize, rng: &mut Rng) -> String {
+    let size = size.max(1);
+    almanac((size / 5).max(1), size, rng)
+}
+
+/// An almanac with `seed_ranges` ranges of seeds, and `layers` layers in every map.
+///
+/// Like in the real almanacs the source ranges of the layers of a map don't overlap, and
+/// everything stays below `2^32`.
+pub(crate) fn almanac(seed_ranges: usize, layers: usize, rng: &mut Rng) -> String {
+    let mut out = String::from("seeds:");
+    for _ in 0..seed_ranges {
+        let start = rng.below(1 << 31);
+        out.push_str(&format!(" {start} {}", rng.between(1, 1 << 28)));
+    }
+    out.push('\n');
+
+    for categories in CATEGORIES.windows(2) {
+        out.push_str(&format!("\n{}-to-{} map:\n", categories[0], categories[1]));
+
+        // every layer gets a slot of the sources, and covers a random part of it
+        let slot_len = (1 << 32) / layers.max(1) as u64;
+        let mut layers = (0..layers as u64)
+            .map(|slot| {
+                let range_len = rng.between(1, slot_len);
+                let source_start = slot * slot_len + rng.below(slot_len - range_len + 1);
+                let dest_start = rng.below((1 << 32) - range_len);
+                (dest_start, source_start, range_len)
+            })
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut layers);
+
+        for (dest_start, source_start, range_len) in layers {
+            out.push_str(&format!("{dest_start} {source_start} {range_len}\n"));
+        }
+    }
+    out
+}
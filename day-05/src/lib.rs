@@ -0,0 +1,652 @@
+use aoc_common::{
+    generate::Rng,
+    interval::IntervalSet,
+    parse::{finish, ParseError},
+    Answer, Solution,
+};
+use nom::{
+    character::complete::{alpha1, newline, space1, u64},
+    combinator::opt,
+    multi::separated_list1,
+    sequence::{delimited, pair, preceded, separated_pair, terminated, tuple},
+    Parser,
+};
+use nom_supreme::{error::ErrorTree, tag::complete::tag, ParserExt};
+use std::{collections::HashMap, ops::Range};
+
+mod generate;
+mod part1;
+mod part2;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Parsed = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::process(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::process(parsed).into()
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate::input(size, rng))
+    }
+}
+
+/// The category every almanac starts at.
+const FIRST_CATEGORY: &str = "seed";
+/// The category every almanac ends at.
+const LAST_CATEGORY: &str = "location";
+
+#[derive(Debug)]
+pub struct Almanac {
+    seeds: Vec<u64>,
+    /// by the category they start at, they lead from [`FIRST_CATEGORY`] to [`LAST_CATEGORY`]
+    /// without going around in circles
+    maps: HashMap<String, Map>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Map {
+    /// category of the numbers the map takes, like `seed`
+    source: String,
+    /// category of the numbers the map gives, like `soil`
+    dest: String,
+    /// sorted by source_start, without overlapping source ranges
+    layers: Vec<MapLayer>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct MapLayer {
+    dest_start: u64,
+    source_start: u64,
+    range_len: u64,
+}
+
+impl MapLayer {
+    fn source_range(&self) -> Range<u64> {
+        self.source_start..self.source_start + self.range_len
+    }
+}
+
+impl Map {
+    /// The map that leaves every number of `category` alone.
+    fn identity(category: &str) -> Map {
+        Map {
+            source: category.to_string(),
+            dest: category.to_string(),
+            layers: Vec::new(),
+        }
+    }
+
+    fn map(&self, source: u64) -> u64 {
+        let idx = self
+            .layers
+            .partition_point(|layer| layer.source_range().end <= source);
+        match self.layers.get(idx) {
+            Some(layer) if layer.source_start <= source => {
+                layer.dest_start + (source - layer.source_start)
+            }
+            _ => source,
+        }
+    }
+
+    /// Where the map cuts the numbers into pieces that are each moved by the same amount.
+    fn breakpoints(&self) -> impl Iterator<Item = u64> + '_ {
+        self.layers
+            .iter()
+            .flat_map(|layer| [layer.source_start, layer.source_range().end])
+    }
+
+    /// Where all the sources in `sources` end up, in one go per piece of the map they fall in.
+    fn map_set(&self, sources: &IntervalSet<u64>) -> IntervalSet<u64> {
+        sources
+            .split(self.breakpoints())
+            .into_iter()
+            .map(|range| {
+                let start = self.map(range.start);
+                start..start + (range.end - range.start)
+            })
+            .collect()
+    }
+
+    /// The map that does this map and then `next`.
+    ///
+    /// Both maps only move numbers below `u64::MAX`, so that's the only number left out.
+    fn then(&self, next: &Map) -> Map {
+        let mut next_breakpoints = next.breakpoints().collect::<Vec<_>>();
+        next_breakpoints.sort_unstable();
+
+        let mut layers: Vec<MapLayer> = Vec::new();
+        for source in IntervalSet::from(0..u64::MAX).split(self.breakpoints()) {
+            let dest_start = self.map(source.start);
+            let dest = dest_start..dest_start + (source.end - source.start);
+            let inside = next_breakpoints.partition_point(|&point| point <= dest.start)
+                ..next_breakpoints.partition_point(|&point| point < dest.end);
+
+            for piece in IntervalSet::from(dest).split(next_breakpoints[inside].iter().copied()) {
+                let layer = MapLayer {
+                    dest_start: next.map(piece.start),
+                    source_start: source.start + (piece.start - dest_start),
+                    range_len: piece.end - piece.start,
+                };
+                match layers.last_mut() {
+                    // the pieces come in order of their sources, so neighbours that are moved
+                    // by the same amount can be merged
+                    Some(last)
+                        if last.source_range().end == layer.source_start
+                            && last.dest_start + last.range_len == layer.dest_start =>
+                    {
+                        last.range_len += layer.range_len
+                    }
+                    _ => layers.push(layer),
+                }
+            }
+        }
+        layers.retain(|layer| layer.dest_start != layer.source_start);
+        Map {
+            source: self.source.clone(),
+            dest: next.dest.clone(),
+            layers,
+        }
+    }
+
+    /// The map the other way around, from destinations back to their sources.
+    fn inverse(&self) -> InverseMap {
+        let mut layers = IntervalSet::from(0..u64::MAX)
+            .split(self.breakpoints())
+            .into_iter()
+            .map(|source| MapLayer {
+                dest_start: source.start,
+                source_start: self.map(source.start),
+                range_len: source.end - source.start,
+            })
+            .collect::<Vec<_>>();
+        layers.sort_unstable_by_key(|layer| layer.source_start);
+        InverseMap { layers }
+    }
+}
+
+/// A [`Map`] the other way around. Layers can map different numbers to the same one, so a
+/// number can have any number of sources.
+#[derive(Debug, PartialEq, Eq)]
+struct InverseMap {
+    /// every piece of the map with its source and destination swapped, sorted by source_start
+    layers: Vec<MapLayer>,
+}
+
+impl InverseMap {
+    /// Every number that the map maps to `dest`, from low to high.
+    fn sources(&self, dest: u64) -> Vec<u64> {
+        if dest == u64::MAX {
+            // the only number maps leave out, it always maps to itself
+            return vec![dest];
+        }
+        let end = self
+            .layers
+            .partition_point(|layer| layer.source_start <= dest);
+        let mut sources = self.layers[..end]
+            .iter()
+            .filter(|layer| layer.source_range().contains(&dest))
+            .map(|layer| layer.dest_start + (dest - layer.source_start))
+            .collect::<Vec<_>>();
+        sources.sort_unstable();
+        sources
+    }
+}
+
+impl Almanac {
+    /// The maps from seeds to locations, in the order they're followed.
+    fn seed_to_location(&self) -> Vec<&Map> {
+        self.route(FIRST_CATEGORY, LAST_CATEGORY)
+            .expect("the maps lead from seed to location, which was checked when parsing")
+    }
+
+    /// All the maps as one, from seeds straight to locations.
+    fn composed(&self) -> Map {
+        self.seed_to_location()
+            .into_iter()
+            .fold(Map::identity(FIRST_CATEGORY), |composed, map| {
+                composed.then(map)
+            })
+    }
+
+    /// The maps followed one after the other, starting at the one from the `from` category.
+    fn maps_from<'a>(&'a self, from: &str) -> impl Iterator<Item = &'a Map> {
+        std::iter::successors(self.maps.get(from), |map| self.maps.get(&map.dest))
+            // there are no circles, but this keeps it from following one forever anyway
+            .take(self.maps.len())
+    }
+
+    /// The maps that lead from the `from` category to the `to` category, `None` if there is no
+    /// way from one to the other.
+    fn route(&self, from: &str, to: &str) -> Option<Vec<&Map>> {
+        if from == to {
+            let known = self
+                .maps
+                .values()
+                .any(|map| map.source == from || map.dest == from);
+            return known.then(Vec::new);
+        }
+        let mut route = Vec::new();
+        for map in self.maps_from(from) {
+            route.push(map);
+            if map.dest == to {
+                return Some(route);
+            }
+        }
+        None
+    }
+
+    /// Every category on the way from seed to location, in that order.
+    pub fn categories(&self) -> impl Iterator<Item = &str> {
+        [FIRST_CATEGORY].into_iter().chain(
+            self.seed_to_location()
+                .into_iter()
+                .map(|map| map.dest.as_str()),
+        )
+    }
+
+    /// Where `number` of the `from` category ends up in the `to` category, like the location of
+    /// a humidity. `None` if the almanac doesn't lead from one to the other.
+    pub fn convert(&self, from: &str, to: &str, number: u64) -> Option<u64> {
+        let route = self.route(from, to)?;
+        Some(route.iter().fold(number, |source, map| map.map(source)))
+    }
+
+    /// The category and number `seed` goes through on its way to its location, starting with
+    /// the seed itself and ending with the location.
+    pub fn chain(&self, seed: u64) -> Vec<(&str, u64)> {
+        let mut number = seed;
+        let mut chain = vec![(FIRST_CATEGORY, seed)];
+        for map in self.seed_to_location() {
+            number = map.map(number);
+            chain.push((map.dest.as_str(), number));
+        }
+        chain
+    }
+
+    /// Every seed that ends up at `location`, from low to high. The seeds don't have to be in
+    /// the almanac.
+    pub fn seeds_at(&self, location: u64) -> Vec<u64> {
+        self.composed().inverse().sources(location)
+    }
+}
+
+fn parse(input: &str) -> Result<Almanac, ParseError> {
+    let seeds = delimited(tag("seeds: "), separated_list1(space1, u64), newline).context("seeds");
+    // a line starting with a number has to be a whole layer
+    let map_layer = tuple((
+        u64,
+        preceded(space1, u64).cut(),
+        preceded(space1, u64).cut(),
+    ))
+    .map_res_cut(|(dest_start, source_start, range_len)| {
+        if source_start
+            .max(dest_start)
+            .checked_add(range_len)
+            .is_none()
+        {
+            return Err("the range goes past the largest number");
+        }
+        Ok(MapLayer {
+            dest_start,
+            source_start,
+            range_len,
+        })
+    })
+    .context("map layer");
+    let map_header = terminated(separated_pair(alpha1, tag("-to-"), alpha1), tag(" map:\n"))
+        .context("map header");
+    let maps = separated_list1(
+        newline,
+        terminated(
+            pair(
+                map_header,
+                separated_list1(newline, map_layer).map_res_cut(check_layers),
+            ),
+            // NOTE: should tecnically be something like newline.or(eof)
+            // but this gives a lot of weird errors
+            opt(newline),
+        )
+        .map(|((source, dest), layers): ((&str, &str), _)| Map {
+            source: source.to_string(),
+            dest: dest.to_string(),
+            layers,
+        })
+        .context("map"),
+    );
+    let mut parser = separated_pair(seeds, newline, maps);
+    let (seeds, maps) = finish::<_, ErrorTree<_>>(input, parser.parse(input))?;
+
+    let maps = check_categories(input, maps)?;
+    Ok(Almanac { seeds, maps })
+}
+
+/// The maps by the category they start at. Every category should have at most one map from it,
+/// the maps shouldn't go around in circles, and they should lead from seed to location.
+fn check_categories(input: &str, maps: Vec<Map>) -> Result<HashMap<String, Map>, ParseError> {
+    let headers = input
+        .lines()
+        .filter(|line| line.ends_with(" map:"))
+        .collect::<Vec<_>>();
+    // where a map into every category is, to point at when the category goes nowhere
+    let mut header_into = HashMap::new();
+    let mut by_source = HashMap::new();
+    let mut sources = Vec::new();
+    for (map, header) in maps.into_iter().zip(&headers) {
+        if by_source.contains_key(&map.source) {
+            let reason = format!("there already is a map from {}", map.source);
+            return Err(ParseError::invalid(input, header, reason));
+        }
+        header_into.entry(map.dest.clone()).or_insert(*header);
+        sources.push(map.source.clone());
+        by_source.insert(map.source.clone(), (map, *header));
+    }
+
+    // every category has at most one map from it, so following the maps from the start of one
+    // that's in a circle leads back to it within as many steps as there are maps
+    for source in &sources {
+        let mut category = source;
+        for _ in 0..by_source.len() {
+            let Some((next, _)) = by_source.get(category) else {
+                break;
+            };
+            category = &next.dest;
+            if category == source {
+                let header = by_source[source].1;
+                let reason = format!("the maps from {source} lead back to {source}");
+                return Err(ParseError::invalid(input, header, reason));
+            }
+        }
+    }
+
+    let mut category = FIRST_CATEGORY;
+    while category != LAST_CATEGORY {
+        let Some((map, _)) = by_source.get(category) else {
+            // point at the map that leads to the dead end, or at the seeds if no map does
+            let at = match header_into.get(category) {
+                Some(header) => header,
+                None => input.lines().next().unwrap_or_default(),
+            };
+            let reason = format!("there is no map from {category}");
+            return Err(ParseError::invalid(input, at, reason));
+        };
+        category = &map.dest;
+    }
+
+    Ok(by_source
+        .into_iter()
+        .map(|(source, (map, _))| (source, map))
+        .collect())
+}
+
+/// The layers sorted by source, as long as their sources don't overlap.
+fn check_layers(mut layers: Vec<MapLayer>) -> Result<Vec<MapLayer>, &'static str> {
+    layers.sort_unstable_by_key(|layer| layer.source_start);
+    if layers
+        .windows(2)
+        .any(|pair| pair[0].source_range().end > pair[1].source_start)
+    {
+        return Err("the source ranges of a map shouldn't overlap");
+    }
+    Ok(layers)
+}
+
+#[test]
+fn parse_range_too_large() {
+    let input = "seeds: 79 14
+
+seed-to-soil map:
+50 98 18446744073709551615";
+    let error = parse(input).unwrap_err();
+    assert_eq!(4, error.location().line);
+}
+
+#[test]
+fn parse_overlapping_layers() {
+    let input = "seeds: 79 14
+
+seed-to-soil map:
+50 98 2
+52 90 9";
+    let error = parse(input).unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("the source ranges of a map shouldn't overlap"));
+}
+
+#[test]
+fn composed_map() {
+    let input = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+    let almanac = parse(input).unwrap();
+    let composed = almanac.composed();
+
+    for seed in 0..120 {
+        let expected = almanac
+            .seed_to_location()
+            .iter()
+            .fold(seed, |source, map| map.map(source));
+        assert_eq!(expected, composed.map(seed), "seed {seed}");
+    }
+    assert_eq!(u64::MAX, composed.map(u64::MAX));
+
+    let chain = almanac.chain(79);
+    assert_eq!(
+        vec![79, 81, 81, 81, 74, 78, 78, 82],
+        chain.iter().map(|&(_, number)| number).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        almanac.categories().collect::<Vec<_>>(),
+        chain
+            .iter()
+            .map(|&(category, _)| category)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(Some(82), almanac.convert("seed", "location", 79));
+    assert_eq!(Some(82), almanac.convert("humidity", "location", 78));
+    assert_eq!(Some(74), almanac.convert("water", "light", 81));
+    assert_eq!(Some(81), almanac.convert("soil", "soil", 81));
+    assert_eq!(None, almanac.convert("location", "seed", 82));
+    assert_eq!(None, almanac.convert("seed", "nowhere", 79));
+    assert_eq!(vec![13], almanac.seeds_at(35));
+    for location in 0..120 {
+        for seed in almanac.seeds_at(location) {
+            assert_eq!(location, composed.map(seed), "seed {seed}");
+        }
+    }
+}
+
+#[test]
+fn inverse_map() {
+    let map = Map {
+        source: "seed".to_string(),
+        dest: "soil".to_string(),
+        layers: vec![
+            MapLayer {
+                dest_start: 98,
+                source_start: 50,
+                range_len: 1,
+            },
+            MapLayer {
+                dest_start: 50,
+                source_start: 98,
+                range_len: 2,
+            },
+        ],
+    };
+    let inverse = map.inverse();
+
+    assert_eq!(vec![98], inverse.sources(50));
+    // 51 is where 99 goes, but it's also left alone by the map
+    assert_eq!(vec![51, 99], inverse.sources(51));
+    assert_eq!(vec![50], inverse.sources(98));
+    assert_eq!(Vec::<u64>::new(), inverse.sources(99));
+    assert_eq!(vec![u64::MAX], inverse.sources(u64::MAX));
+}
+
+#[test]
+fn parse_missing_map() {
+    let input = "seeds: 79 14
+
+seed-to-soil map:
+50 98 2
+
+fertilizer-to-location map:
+50 98 2";
+    let error = parse(input).unwrap_err();
+    assert_eq!(3, error.location().line);
+    assert!(error.to_string().contains("there is no map from soil"));
+
+    let input = "seeds: 79 14
+
+soil-to-location map:
+50 98 2";
+    let error = parse(input).unwrap_err();
+    assert_eq!(1, error.location().line);
+    assert!(error.to_string().contains("there is no map from seed"));
+}
+
+#[test]
+fn parse_duplicate_map() {
+    let input = "seeds: 79 14
+
+seed-to-location map:
+50 98 2
+
+seed-to-location map:
+50 98 2";
+    let error = parse(input).unwrap_err();
+    assert_eq!(6, error.location().line);
+    assert!(error
+        .to_string()
+        .contains("there already is a map from seed"));
+}
+
+#[test]
+fn shuffled_maps() {
+    let input = "seeds: 79 14 55 13
+
+water-to-light map:
+88 18 7
+18 25 70
+
+humidity-to-location map:
+60 56 37
+56 93 4
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15";
+    let almanac = parse(input).unwrap();
+    assert_eq!(35, part1::process(&almanac));
+    assert_eq!(Some(46), part2::process(&almanac));
+    assert_eq!(
+        vec![
+            "seed",
+            "soil",
+            "fertilizer",
+            "water",
+            "light",
+            "temperature",
+            "humidity",
+            "location"
+        ],
+        almanac.categories().collect::<Vec<_>>()
+    );
+    assert_eq!(Some(82), almanac.convert("humidity", "location", 78));
+}
+
+#[test]
+fn parse_circular_maps() {
+    let input = "seeds: 79 14
+
+seed-to-soil map:
+50 98 2
+
+soil-to-water map:
+50 98 2
+
+water-to-soil map:
+50 98 2
+
+location-to-seed map:
+50 98 2";
+    let error = parse(input).unwrap_err();
+    assert_eq!(6, error.location().line);
+    assert!(error
+        .to_string()
+        .contains("the maps from soil lead back to soil"));
+}
+
+#[test]
+fn parse_not_ending_at_location() {
+    let input = "seeds: 79 14
+
+seed-to-soil map:
+50 98 2";
+    let error = parse(input).unwrap_err();
+    assert_eq!(3, error.location().line);
+    assert!(error.to_string().contains("there is no map from soil"));
+}
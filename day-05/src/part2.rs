@@ -0,0 +1,173 @@
+use aoc_common::interval::IntervalSet;
+
+use crate::Almanac;
+
+/// Lowest location of any seed in the ranges of seeds, `None` if the seeds don't come in pairs of
+/// a start and a length, if a range goes past the largest number or if all the ranges are empty.
+pub(crate) fn process(input: &Almanac) -> Option<u64> {
+    let seeds = input
+        .seeds
+        .chunks(2)
+        .map(|pair| match *pair {
+            [start, len] => Some(start..start.checked_add(len)?),
+            _ => None,
+        })
+        .collect::<Option<IntervalSet<u64>>>()?;
+
+    input.composed().map_set(&seeds).min()
+}
+
+#[test]
+fn example() {
+    let input = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+    let expected = 46;
+
+    let parsed = crate::parse(input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(Some(expected), output);
+}
+
+#[test]
+fn range_past_last_layer() {
+    // seeds 20 to 32 are after the only layer, so they keep their numbers
+    let input = "seeds: 19 14
+
+seed-to-location map:
+6 4 16";
+
+    let parsed = crate::parse(input).unwrap();
+    assert_eq!(Some(20), process(&parsed));
+}
+
+#[test]
+fn bad_seed_ranges() {
+    let almanac = |seeds| {
+        let input = format!("seeds: {seeds}\n\nseed-to-location map:\n6 4 16");
+        crate::parse(&input).unwrap()
+    };
+
+    // these are all fine for part 1, but not as ranges
+    assert_eq!(16, crate::part1::process(&almanac("79 14 55")));
+    assert_eq!(None, process(&almanac("79 14 55")));
+    assert_eq!(None, process(&almanac("79 0")));
+    assert_eq!(None, process(&almanac("18446744073709551615 1")));
+    // an empty range is fine if there are seeds in the others
+    assert_eq!(Some(55), process(&almanac("79 0 55 1")));
+}
+
+#[test]
+fn real_input() {
+    let Some((input, expected)) = aoc_common::known_answers::real_answer(5, 2) else {
+        return;
+    };
+
+    let parsed = crate::parse(&input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(expected, aoc_common::Answer::from(output).to_string());
+}
+
+/// Part 2 the slow way, by mapping every seed in the ranges on its own like part 1 does.
+#[cfg(test)]
+fn brute_force(input: &Almanac) -> Option<u64> {
+    let maps = input.seed_to_location();
+    input
+        .seeds
+        .chunks_exact(2)
+        .flat_map(|pair| pair[0]..pair[0] + pair[1])
+        .map(|seed| maps.iter().fold(seed, |source, map| map.map(source)))
+        .min()
+}
+
+/// Small almanacs, with layers that don't overlap like in the real ones.
+#[cfg(test)]
+fn almanac() -> impl proptest::strategy::Strategy<Value = Almanac> {
+    use crate::{generate::CATEGORIES, Map, MapLayer};
+    use proptest::{collection::vec, prelude::*};
+
+    // a gap before every layer, its length and where it maps to
+    let layers = vec((0u64..20, 1u64..20, 0u64..100), 0..5).prop_map(|layers| {
+        let mut source_start = 0;
+        layers
+            .into_iter()
+            .map(|(gap, range_len, dest_start)| {
+                source_start += gap;
+                let layer = MapLayer {
+                    dest_start,
+                    source_start,
+                    range_len,
+                };
+                source_start += range_len;
+                layer
+            })
+            .collect::<Vec<_>>()
+    });
+    let seed_ranges = vec((0u64..150, 1u64..30), 1..4);
+
+    (seed_ranges, vec(layers, 1..4)).prop_map(|(seed_ranges, maps)| {
+        let n_maps = maps.len();
+        Almanac {
+            seeds: seed_ranges
+                .into_iter()
+                .flat_map(|(start, len)| [start, len])
+                .collect(),
+            maps: maps
+                .into_iter()
+                .enumerate()
+                .map(|(idx, layers)| {
+                    // the last map has to end at location, wherever it starts
+                    let dest = if idx + 1 == n_maps {
+                        crate::LAST_CATEGORY
+                    } else {
+                        CATEGORIES[idx + 1]
+                    };
+                    let map = Map {
+                        source: CATEGORIES[idx].to_string(),
+                        dest: dest.to_string(),
+                        layers,
+                    };
+                    (map.source.clone(), map)
+                })
+                .collect(),
+        }
+    })
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn same_as_brute_force(almanac in almanac()) {
+        proptest::prop_assert_eq!(brute_force(&almanac), process(&almanac));
+    }
+}
@@ -0,0 +1,34 @@
+use aoc_common::generate::Rng;
+
+/// `size` races, but at most 4, as part 2 joins the digits of all of them into one big race.
+///
+/// Every record can be beaten, also the one of the big race.
+pub(crate) fn input(size: usize, rng: &mut Rng) -> String {
+    let races = size.clamp(1, 4);
+    loop {
+        let times = (0..races).map(|_| rng.between(7, 99)).collect::<Vec<_>>();
+        let distances = times
+            .iter()
+            .map(|time| rng.below(best_distance(*time)))
+            .collect::<Vec<_>>();
+
+        let join = |numbers: &[u64]| numbers.iter().map(u64::to_string).collect::<String>();
+        let (time, distance) = (join(&times), join(&distances));
+        if distance.parse::<u64>().unwrap() >= best_distance(time.parse().unwrap()) {
+            continue;
+        }
+
+        let list = |numbers: &[u64]| {
+            numbers
+                .iter()
+                .map(|number| format!("{number:>5}"))
+                .collect::<String>()
+        };
+        return format!("Time:    {}\nDistance:{}\n", list(&times), list(&distances));
+    }
+}
+
+/// The furthest a boat can go in `time`.
+fn best_distance(time: u64) -> u64 {
+    (time / 2) * (time - time / 2)
+}
@@ -0,0 +1,208 @@
+use aoc_common::{
+    generate::Rng,
+    parse::{finish, ParseError},
+    Answer, Solution,
+};
+use nom::{
+    character::complete::{line_ending, space0, space1, u64},
+    combinator::opt,
+    multi::separated_list1,
+    sequence::separated_pair,
+    Parser,
+};
+use nom_supreme::{error::ErrorTree, tag::complete::tag, ParserExt};
+use std::ops::RangeInclusive;
+
+mod generate;
+mod model;
+mod part1;
+mod part2;
+
+pub use model::BoatModel;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Parsed = BoatRaces;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::process(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::process(parsed).into()
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate::input(size, rng))
+    }
+}
+
+#[derive(Debug)]
+pub struct BoatRaces {
+    times: Vec<u64>,
+    distances: Vec<u64>,
+}
+
+impl BoatRaces {
+    pub fn races(&self) -> impl Iterator<Item = BoatRace> + '_ {
+        self.times
+            .iter()
+            .zip(&self.distances)
+            .map(|(&time, &distance)| BoatRace { time, distance })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoatRace {
+    pub time: u64,
+    /// the record to beat
+    pub distance: u64,
+}
+
+impl BoatRace {
+    /// How far the boat goes when the button is held for `hold` of the race's time.
+    fn distance_after(&self, hold: u64) -> u128 {
+        let hold = u128::from(hold.min(self.time));
+        hold * (u128::from(self.time) - hold)
+    }
+
+    fn beats_record(&self, hold: u64) -> bool {
+        self.distance_after(hold) > u128::from(self.distance)
+    }
+
+    /// Every time the button can be held to beat the record, `None` if it can't be beaten.
+    pub fn winning_holds(&self) -> Option<RangeInclusive<u64>> {
+        // with x = hold, t = time and d = distance, the boat goes x * (t - x), which is more
+        // than d between the roots of x^2 - t*x + d, (t +- sqrt(t^2 - 4d)) / 2
+        let time = u128::from(self.time);
+        let discriminant = (time * time).checked_sub(4 * u128::from(self.distance))?;
+        let lower_root = ((time - discriminant.isqrt()) / 2) as u64;
+
+        // the integer square root is rounded down, so the first hold past the lower root can
+        // be a bit off
+        let mut first = lower_root;
+        while first > 0 && self.beats_record(first - 1) {
+            first -= 1;
+        }
+        while first <= self.time / 2 && !self.beats_record(first) {
+            first += 1;
+        }
+
+        // the distances are symmetric around half the time
+        let last = self.time.checked_sub(first)?;
+        (first <= last).then_some(first..=last)
+    }
+
+    /// Number of ways to beat the record.
+    pub fn ways_to_win(&self) -> u64 {
+        self.winning_holds()
+            .map_or(0, |holds| holds.end() - holds.start() + 1)
+    }
+}
+
+fn parse(input: &str) -> Result<BoatRaces, ParseError> {
+    let list = |name: &'static str, context: &'static str| {
+        tag(name)
+            .precedes(space0)
+            .precedes(separated_list1(space1::<&str, ErrorTree<&str>>, u64))
+            .context(context)
+    };
+    let mut parser = separated_pair(
+        list("Time:", "times"),
+        line_ending,
+        list("Distance:", "distances"),
+    )
+    .terminated(opt(line_ending))
+    .map_res(|(times, distances)| {
+        if times.len() != distances.len() {
+            return Err("there should be a distance for every time");
+        }
+        Ok(BoatRaces { times, distances })
+    });
+
+    finish(input, parser.parse(input))
+}
+
+#[test]
+fn parse_example() {
+    let example_input = "Time:      7  15   30
+Distance:  9  40  200";
+    parse(example_input).unwrap();
+}
+
+#[test]
+fn parse_missing_distance() {
+    let input = "Time:      7  15   30
+Distance:  9  40";
+    let error = parse(input).unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("there should be a distance for every time"));
+}
+
+#[test]
+fn parse_real_input() {
+    let Some(real_input) = aoc_common::input::real_input(6) else {
+        return;
+    };
+    parse(&real_input).unwrap();
+}
+
+#[test]
+fn winning_holds() {
+    let race = |time, distance| BoatRace { time, distance };
+
+    assert_eq!(Some(2..=5), race(7, 9).winning_holds());
+    assert_eq!(Some(11..=19), race(30, 200).winning_holds());
+    // holding for exactly half the time only ties the record
+    assert_eq!(None, race(4, 4).winning_holds());
+    assert_eq!(Some(2..=2), race(4, 3).winning_holds());
+    assert_eq!(None, race(0, 0).winning_holds());
+    assert_eq!(Some(1..=u64::MAX - 1), race(u64::MAX, 0).winning_holds());
+    assert_eq!(0, race(10, u64::MAX).ways_to_win());
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn winning_holds_are_exact(time in 0..=u64::MAX, distance in 0..=u64::MAX) {
+        let race = BoatRace { time, distance };
+        match race.winning_holds() {
+            Some(holds) => {
+                proptest::prop_assert!(race.beats_record(*holds.start()));
+                proptest::prop_assert!(race.beats_record(*holds.end()));
+                proptest::prop_assert!(!race.beats_record(holds.start() - 1));
+                proptest::prop_assert!(!race.beats_record(holds.end() + 1));
+            }
+            None => proptest::prop_assert!(!race.beats_record(time / 2)),
+        }
+    }
+}
+
+/// Helpers for the tests of the parts.
+#[cfg(test)]
+mod tests {
+    /// Number of ways to beat `distance` in `time`, by trying every way of holding the button.
+    pub(crate) fn brute_force(time: u64, distance: u64) -> u64 {
+        (0..=time)
+            .filter(|hold| hold * (time - hold) > distance)
+            .count() as u64
+    }
+
+    /// A race of up to `max_time` with a record that can be beaten, as `(time, distance)`.
+    pub(crate) fn race(max_time: u64) -> impl proptest::strategy::Strategy<Value = (u64, u64)> {
+        use proptest::prelude::*;
+
+        (2..=max_time).prop_flat_map(|time| {
+            let best_distance = (time / 2) * (time - time / 2);
+            (Just(time), 0..best_distance)
+        })
+    }
+}
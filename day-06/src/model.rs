@@ -0,0 +1,224 @@
+//! Boats that don't work like the ones in the puzzle, to see what would happen if they did.
+
+use std::ops::RangeInclusive;
+
+use aoc_common::parse::{finish, lines, ParseError};
+use nom::{
+    character::complete::{line_ending, space0, u64},
+    sequence::separated_pair,
+    Parser,
+};
+use nom_supreme::{error::ErrorTree, tag::complete::tag, ParserExt};
+
+use crate::{BoatRace, BoatRaces};
+
+/// How fast a boat goes for how long the button is held. The [`Default`] is the boat of the
+/// puzzle, which gets 1 mm/ms faster for every ms the button is held.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoatModel {
+    /// speed gained for every ms the button is held, in mm/ms
+    pub acceleration: u64,
+    /// speed the boat can't go faster than, in mm/ms
+    pub max_speed: Option<u64>,
+    /// ms the boat waits after the button is let go before it moves
+    pub charge_cost: u64,
+}
+
+impl Default for BoatModel {
+    fn default() -> BoatModel {
+        BoatModel {
+            acceleration: 1,
+            max_speed: None,
+            charge_cost: 0,
+        }
+    }
+}
+
+impl BoatModel {
+    /// How fast the boat goes when the button is held for `hold`.
+    pub fn speed(&self, hold: u64) -> u128 {
+        let speed = u128::from(self.acceleration) * u128::from(hold);
+        match self.max_speed {
+            Some(max_speed) => speed.min(u128::from(max_speed)),
+            None => speed,
+        }
+    }
+
+    /// How far the boat goes in a race of `time` when the button is held for `hold`.
+    pub fn distance(&self, time: u64, hold: u64) -> u128 {
+        let moving = time.saturating_sub(hold).saturating_sub(self.charge_cost);
+        self.speed(hold).saturating_mul(u128::from(moving))
+    }
+
+    fn beats_record(&self, race: &BoatRace, hold: u64) -> bool {
+        self.distance(race.time, hold) > u128::from(race.distance)
+    }
+
+    /// Every time the button can be held to beat the record of `race`, `None` if it can't be
+    /// beaten.
+    ///
+    /// Without a speed limit the distance is a quadratic in the time held, which is solved
+    /// exactly. With one, the distance first goes up and then down, so the times are found by
+    /// binary searching either side of the furthest distance.
+    pub fn winning_holds(&self, race: &BoatRace) -> Option<RangeInclusive<u64>> {
+        if self.acceleration == 0 || self.max_speed == Some(0) {
+            return None;
+        }
+        // holding any longer than this only leaves time for waiting
+        let time = race.time.saturating_sub(self.charge_cost);
+
+        let reaches_max_speed = self.max_speed.is_some_and(|max_speed| {
+            u128::from(self.acceleration) * u128::from(time) > u128::from(max_speed)
+        });
+        if !reaches_max_speed {
+            // a * x * (t - x) > d, with whole numbers on the left, is the same as
+            // x * (t - x) > d / a rounded down
+            let puzzle_race = BoatRace {
+                time,
+                distance: race.distance / self.acceleration,
+            };
+            return puzzle_race.winning_holds();
+        }
+
+        // the distance only stops growing at the furthest distance
+        let furthest = first_where(0, time, |hold| {
+            hold == time || self.distance(race.time, hold) >= self.distance(race.time, hold + 1)
+        });
+        if !self.beats_record(race, furthest) {
+            return None;
+        }
+        let first = first_where(0, furthest, |hold| self.beats_record(race, hold));
+        let past_last = first_where(furthest, time, |hold| {
+            hold == time || !self.beats_record(race, hold + 1)
+        });
+        Some(first..=past_last)
+    }
+
+    /// Number of ways to beat the record of `race`.
+    pub fn ways_to_win(&self, race: &BoatRace) -> u64 {
+        self.winning_holds(race)
+            .map_or(0, |holds| holds.end() - holds.start() + 1)
+    }
+
+    /// Product of the number of ways to win every race, `None` if it doesn't fit in a `u64`.
+    pub fn ways_to_win_all(&self, races: &BoatRaces) -> Option<u64> {
+        races
+            .races()
+            .map(|race| self.ways_to_win(&race))
+            .try_fold(1u64, u64::checked_mul)
+    }
+}
+
+/// The first number in `low..=high` for which `is_past` is true, with `is_past` being false up
+/// to some number and true after it, and true for `high`.
+fn first_where(mut low: u64, mut high: u64, is_past: impl Fn(u64) -> bool) -> u64 {
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if is_past(mid) {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+    low
+}
+
+impl BoatRaces {
+    /// Races from a table with a time and distance on every line, like:
+    ///
+    /// ```text
+    /// time,distance
+    /// 7,9
+    /// 15,40
+    /// ```
+    pub fn from_csv(input: &str) -> Result<BoatRaces, ParseError> {
+        let race = separated_pair(u64, tag(",").delimited_by(space0), u64).context("race");
+        let mut parser = tag("time,distance")
+            .context("header")
+            .precedes(line_ending)
+            .precedes(lines::<_, ErrorTree<&str>, _>(race))
+            .map(|races| {
+                let (times, distances) = races.into_iter().unzip();
+                BoatRaces { times, distances }
+            });
+
+        finish(input, parser.parse(input))
+    }
+}
+
+#[test]
+fn csv_races() {
+    let input = "time,distance
+7,9
+15, 40
+30 ,200
+";
+    let races = BoatRaces::from_csv(input).unwrap();
+    assert_eq!(vec![7, 15, 30], races.times);
+    assert_eq!(vec![9, 40, 200], races.distances);
+    assert_eq!(Some(288), BoatModel::default().ways_to_win_all(&races));
+
+    let error = BoatRaces::from_csv("time,distance\n7;9").unwrap_err();
+    assert_eq!(2, error.location().line);
+}
+
+#[test]
+fn other_boats() {
+    let race = BoatRace {
+        time: 30,
+        distance: 200,
+    };
+    assert_eq!(Some(11..=19), BoatModel::default().winning_holds(&race));
+
+    let faster = BoatModel {
+        acceleration: 2,
+        ..BoatModel::default()
+    };
+    assert_eq!(Some(4..=26), faster.winning_holds(&race));
+
+    // holding for 10 ms gets the boat to 10 mm/ms, and then there are 20 ms left to go 200 mm
+    let limited = BoatModel {
+        max_speed: Some(10),
+        ..BoatModel::default()
+    };
+    assert_eq!(None, limited.winning_holds(&race));
+    let limited = BoatModel {
+        max_speed: Some(11),
+        ..BoatModel::default()
+    };
+    assert_eq!(Some(11..=11), limited.winning_holds(&race));
+
+    let slow_start = BoatModel {
+        charge_cost: 1,
+        ..BoatModel::default()
+    };
+    assert_eq!(Some(12..=17), slow_start.winning_holds(&race));
+
+    let broken = BoatModel {
+        acceleration: 0,
+        ..BoatModel::default()
+    };
+    assert_eq!(0, broken.ways_to_win(&race));
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn same_as_brute_force(
+        acceleration in 0u64..5,
+        max_speed in proptest::option::of(0u64..60),
+        charge_cost in 0u64..10,
+        time in 0u64..100,
+        distance in 0u64..2000,
+    ) {
+        let model = BoatModel { acceleration, max_speed, charge_cost };
+        let race = BoatRace { time, distance };
+
+        let winning = (0..=time)
+            .filter(|&hold| model.beats_record(&race, hold))
+            .collect::<Vec<_>>();
+        let expected = winning.first().zip(winning.last()).map(|(&first, &last)| first..=last);
+        proptest::prop_assert_eq!(expected, model.winning_holds(&race));
+        proptest::prop_assert_eq!(winning.len() as u64, model.ways_to_win(&race));
+    }
+}
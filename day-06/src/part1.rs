@@ -0,0 +1,45 @@
+use crate::BoatRaces;
+
+/// Product of the number of ways to win every race, `None` if it doesn't fit in a `u64`.
+pub(crate) fn process(input: &BoatRaces) -> Option<u64> {
+    input
+        .races()
+        .map(|race| race.ways_to_win())
+        .try_fold(1u64, u64::checked_mul)
+}
+
+#[test]
+fn example() {
+    let input = "Time:      7  15   30
+Distance:  9  40  200";
+    let expected = 288;
+
+    let parsed = crate::parse(input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(Some(expected), output);
+}
+
+#[test]
+fn real_input() {
+    let Some((input, expected)) = aoc_common::known_answers::real_answer(6, 1) else {
+        return;
+    };
+
+    let parsed = crate::parse(&input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(expected, aoc_common::Answer::from(output).to_string());
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn same_as_brute_force(races in proptest::collection::vec(crate::tests::race(100), 1..5)) {
+        let expected = races
+            .iter()
+            .map(|&(time, distance)| crate::tests::brute_force(time, distance))
+            .product::<u64>();
+        let (times, distances) = races.into_iter().unzip();
+
+        proptest::prop_assert_eq!(Some(expected), process(&BoatRaces { times, distances }));
+    }
+}
@@ -0,0 +1,81 @@
+use std::num::ParseIntError;
+
+use crate::{BoatRace, BoatRaces};
+
+/// Number of ways to win the race, `None` if its time or distance don't fit in a `u64`.
+pub(crate) fn process(input: &BoatRaces) -> Option<u64> {
+    let race = BoatRace::try_from(input).ok()?;
+    Some(race.ways_to_win())
+}
+
+/// The spaces between the numbers were just bad kerning.
+impl TryFrom<&BoatRaces> for BoatRace {
+    type Error = ParseIntError;
+
+    fn try_from(races: &BoatRaces) -> Result<Self, Self::Error> {
+        let join = |numbers: &[u64]| {
+            numbers
+                .iter()
+                .map(u64::to_string)
+                .collect::<String>()
+                .parse()
+        };
+
+        Ok(BoatRace {
+            time: join(&races.times)?,
+            distance: join(&races.distances)?,
+        })
+    }
+}
+
+#[test]
+fn example() {
+    let input = "Time:      7  15   30
+Distance:  9  40  200";
+    let expected = 71503;
+
+    let parsed = crate::parse(input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(Some(expected), output);
+}
+
+#[test]
+fn big_race() {
+    // f32 isn't precise enough to find the roots of this one
+    let input = "Time:      45545
+Distance:  337643837";
+
+    let parsed = crate::parse(input).unwrap();
+    assert_eq!(Some(26904), process(&parsed));
+}
+
+#[test]
+fn real_input() {
+    let Some((input, expected)) = aoc_common::known_answers::real_answer(6, 2) else {
+        return;
+    };
+
+    let parsed = crate::parse(&input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(expected, aoc_common::Answer::from(output).to_string());
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn same_as_brute_force((time, distance) in crate::tests::race(1_000_000)) {
+        let races = BoatRaces {
+            times: vec![time],
+            distances: vec![distance],
+        };
+        proptest::prop_assert_eq!(Some(crate::tests::brute_force(time, distance)), process(&races));
+    }
+}
+
+#[test]
+fn joined_time_too_large() {
+    let input = "Time:      1000000000 1000000000 1000000000
+Distance:  9 40 200";
+    let parsed = crate::parse(input).unwrap();
+    assert_eq!(None, process(&parsed));
+}
@@ -0,0 +1,22 @@
+use aoc_common::generate::Rng;
+
+const CARDS: [char; 13] = [
+    '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
+];
+
+/// `size` hands, with bids up to 1000.
+///
+/// Hands are drawn from a few cards at a time, so that there are enough pairs and better.
+pub(crate) fn input(size: usize, rng: &mut Rng) -> String {
+    let size = size.max(1);
+    let mut out = String::new();
+    for _ in 0..size {
+        let mut cards = CARDS;
+        rng.shuffle(&mut cards);
+        let cards = &cards[..rng.between(2, 5) as usize];
+
+        let hand = (0..5).map(|_| *rng.pick(cards)).collect::<String>();
+        out.push_str(&format!("{hand} {}\n", rng.between(1, 1000)));
+    }
+    out
+}
@@ -0,0 +1,150 @@
+use aoc_common::{
+    generate::Rng,
+    parse::{finish, lines, ParseError},
+    Answer, Solution,
+};
+use nom::{
+    character::complete::{alphanumeric1, space1, u32},
+    sequence::separated_pair,
+    Parser,
+};
+use nom_supreme::{error::ErrorTree, ParserExt};
+use std::str::FromStr;
+
+mod generate;
+mod part1;
+mod part2;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Parsed = Game;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::process(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::process(parsed).into()
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate::input(size, rng))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Card {
+    value: u8,
+}
+
+impl TryFrom<char> for Card {
+    type Error = anyhow::Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        let value = match (value.to_digit(10), value) {
+            (Some(n), _) if (2..=9).contains(&n) => n as u8,
+            (None, 'T') => 10,
+            (None, 'J') => 11,
+            (None, 'Q') => 12,
+            (None, 'K') => 13,
+            (None, 'A') => 14,
+            _ => anyhow::bail!("invalid card {value}"),
+        };
+
+        Ok(Card { value })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Hand([Card; 5]);
+
+impl FromStr for Hand {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Hand(
+            s.chars()
+                .map(Card::try_from)
+                .collect::<Result<Vec<_>, _>>()?
+                .try_into()
+                .map_err(|e: Vec<_>| anyhow::anyhow!("unexpected hand length: {}", e.len()))?,
+        ))
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Game {
+    hands: Vec<Hand>,
+    bids: Vec<u32>,
+}
+
+impl Game {
+    /// Total winnings, when the hands are ranked by `hand_type`, then by their cards.
+    fn total_winnings(&self, hand_type: impl Fn(&Hand) -> HandType) -> u64 {
+        let hands_types = self.hands.iter().map(hand_type);
+
+        let mut ranked: Vec<_> = hands_types.zip(&self.hands).zip(&self.bids).collect();
+        ranked.sort_unstable_by(|((htype1, hand1), _), ((htype2, hand2), _)| {
+            htype1.cmp(htype2).then(hand1.cmp(hand2))
+        });
+        ranked
+            .into_iter()
+            .map(|(_, bid)| bid)
+            .enumerate()
+            .fold(0, |sum, (rank, &bid)| {
+                sum + (rank + 1) as u64 * u64::from(bid)
+            })
+    }
+}
+
+fn parse(input: &str) -> Result<Game, ParseError> {
+    let line = separated_pair(
+        alphanumeric1::<_, ErrorTree<&str>>
+            .map_res(Hand::from_str)
+            .context("hand"),
+        space1,
+        u32.context("bid"),
+    );
+    let mut parser = lines(line.context("hand and bid")).map(|lines| {
+        let (hands, bids) = lines.into_iter().unzip();
+        Game { hands, bids }
+    });
+
+    finish(input, parser.parse(input))
+}
+
+#[test]
+fn parse_example() {
+    let example_input = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+    parse(example_input).unwrap();
+}
+
+#[test]
+fn parse_real_input() {
+    let Some(real_input) = aoc_common::input::real_input(7) else {
+        return;
+    };
+    parse(&real_input).unwrap();
+}
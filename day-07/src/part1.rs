@@ -0,0 +1,58 @@
+use std::collections::HashMap;
+
+use crate::{Game, Hand, HandType};
+
+pub(crate) fn process(input: &Game) -> u64 {
+    input.total_winnings(get_hand_type)
+}
+
+fn get_hand_type(hand: &Hand) -> HandType {
+    let mut counters = hand
+        .0
+        .iter()
+        .map(|card| card.value)
+        .fold(HashMap::new(), |mut map, card| {
+            let counter = map.get(&card);
+            map.insert(card, counter.unwrap_or(&0) + 1);
+            map
+        })
+        .into_values()
+        .collect::<Vec<_>>();
+    counters.sort_unstable();
+
+    match counters[..] {
+        [5] => HandType::FiveOfAKind,
+        [1, 4] => HandType::FourOfAKind,
+        [2, 3] => HandType::FullHouse,
+        [1, 1, 3] => HandType::ThreeOfAKind,
+        [1, 2, 2] => HandType::TwoPair,
+        [1, 1, 1, 2] => HandType::OnePair,
+        [1, 1, 1, 1, 1] => HandType::HighCard,
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn example() {
+    let input = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+    let expected = 6440;
+
+    let parsed = crate::parse(input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(expected, output);
+}
+
+#[test]
+fn real_input() {
+    let Some((input, expected)) = aoc_common::known_answers::real_answer(7, 1) else {
+        return;
+    };
+
+    let parsed = crate::parse(&input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(expected, aoc_common::Answer::from(output).to_string());
+}
@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+
+use crate::{Card, Game, Hand, HandType};
+
+const JOKER: Card = Card { value: 1 };
+
+pub(crate) fn process(input: &Game) -> u64 {
+    let game = Game {
+        hands: input.hands.iter().map(Hand::with_jokers).collect(),
+        bids: input.bids.clone(),
+    };
+    game.total_winnings(get_hand_type)
+}
+
+impl Hand {
+    /// Js are jokers now, which are worth less than any other card.
+    fn with_jokers(&self) -> Hand {
+        let jack = Card::try_from('J').unwrap();
+        Hand(
+            self.0
+                .clone()
+                .map(|card| if card == jack { JOKER } else { card }),
+        )
+    }
+}
+
+fn get_hand_type(hand: &Hand) -> HandType {
+    let mut num_j = 0;
+    let mut counters = hand
+        .0
+        .iter()
+        .map(|card| card.value)
+        .fold(HashMap::new(), |mut map, card| {
+            if card == JOKER.value {
+                num_j += 1
+            } else {
+                let counter = map.get(&card);
+                map.insert(card, counter.unwrap_or(&0) + 1);
+            }
+            map
+        })
+        .into_values()
+        .collect::<Vec<_>>();
+    counters.sort_unstable();
+
+    if let Some(max) = counters.last_mut() {
+        // Js contribute to max as this gives highes hand type
+        *max += num_j;
+    } else {
+        // if no elems in counters => 5 Js => 5OK
+        return HandType::FiveOfAKind;
+    }
+
+    match counters[..] {
+        [5] => HandType::FiveOfAKind,
+        [1, 4] => HandType::FourOfAKind,
+        [2, 3] => HandType::FullHouse,
+        [1, 1, 3] => HandType::ThreeOfAKind,
+        [1, 2, 2] => HandType::TwoPair,
+        [1, 1, 1, 2] => HandType::OnePair,
+        [1, 1, 1, 1, 1] => HandType::HighCard,
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn example() {
+    let input = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+    let expected = 5905;
+
+    let parsed = crate::parse(input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(expected, output);
+}
+
+#[test]
+fn real_input() {
+    let Some((input, expected)) = aoc_common::known_answers::real_answer(7, 2) else {
+        return;
+    };
+
+    let parsed = crate::parse(&input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(expected, aoc_common::Answer::from(output).to_string());
+}
@@ -0,0 +1,51 @@
+use aoc_common::generate::Rng;
+
+/// Chars for the names of the nodes in between, which mustn't end in an `A` or `Z`.
+const NAME_CHARS: &[u8] = b"BCDEFGHIJKLMNOPQRSTUVWXY0123456789";
+
+/// A network of up to `size` nodes, with up to 6 ghosts starting at `AAA`, `11A`, `22A`, ...
+///
+/// Every ghost walks around its own loop, which ends at its `Z` node, whatever the instructions
+/// are. It takes as many steps to reach the `Z` node the first time as it takes to get back to
+/// it, so that the steps of the ghosts can be combined with their least common multiple.
+pub(crate) fn input(size: usize, rng: &mut Rng) -> String {
+    let instructions = (0..rng.between(2, 300))
+        .map(|_| if rng.chance(0.5) { 'L' } else { 'R' })
+        .collect::<String>();
+
+    let ghosts = (size / 50).clamp(1, 6);
+    let mut inner_names = (0..).map(|idx: usize| {
+        [idx / 34 / 34 % 34, idx / 34 % 34, idx % 34]
+            .map(|digit| char::from(NAME_CHARS[digit]))
+            .iter()
+            .collect::<String>()
+    });
+
+    let mut junctions = Vec::new();
+    for ghost in 0..ghosts {
+        let c = if ghost == 0 {
+            'A'
+        } else {
+            char::from(b'0' + ghost as u8)
+        };
+        let start = format!("{c}{c}A");
+        let end = if ghost == 0 {
+            "ZZZ".to_string()
+        } else {
+            format!("{c}{c}Z")
+        };
+
+        // start -> inner... -> end -> first inner
+        let steps = rng.between(2, (size / ghosts).max(2) as u64) as usize;
+        let inner = inner_names.by_ref().take(steps - 1).collect::<Vec<_>>();
+        let mut path = [vec![start], inner.clone(), vec![end]].concat();
+        path.push(inner[0].clone());
+
+        for pair in path.windows(2) {
+            junctions.push(format!("{} = ({}, {})", pair[0], pair[1], pair[1]));
+        }
+    }
+    rng.shuffle(&mut junctions);
+
+    format!("{instructions}\n\n{}\n", junctions.join("\n"))
+}
@@ -0,0 +1,247 @@
+use aoc_common::{
+    generate::Rng,
+    parse::{finish, lines, ParseError},
+    Answer, Solution,
+};
+use nom::{
+    character::complete::{alphanumeric1, anychar, line_ending},
+    multi::many1,
+    sequence::{delimited, separated_pair},
+    Parser,
+};
+use nom_supreme::{error::ErrorTree, tag::complete::tag, ParserExt};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    str::FromStr,
+};
+
+mod generate;
+mod part1;
+mod part2;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Parsed = ParsedData;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::process(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::process(parsed).into()
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate::input(size, rng))
+    }
+}
+
+#[derive(Debug)]
+enum Direction {
+    Left,
+    Right,
+}
+
+impl TryFrom<char> for Direction {
+    type Error = anyhow::Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'L' => Ok(Direction::Left),
+            'R' => Ok(Direction::Right),
+            c => Err(anyhow::anyhow!("invalid char: {c}")),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+struct Node([char; 3]);
+
+impl FromStr for Node {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next(), chars.next(), chars.next()) {
+            (Some(a), Some(b), Some(c), None) => Ok(Node([a, b, c])),
+            _ => anyhow::bail!("node names have 3 chars, got {s:?}"),
+        }
+    }
+}
+
+impl Display for Node {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let [a, b, c] = self.0;
+        write!(f, "{a}{b}{c}")
+    }
+}
+
+#[derive(Debug)]
+struct Junction {
+    left: Node,
+    right: Node,
+}
+
+impl Junction {
+    #[inline]
+    fn go(&self, direction: &Direction) -> &Node {
+        match direction {
+            Direction::Left => &self.left,
+            Direction::Right => &self.right,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ParsedData {
+    instructions: Vec<Direction>,
+    map: HashMap<Node, Junction>,
+}
+
+impl ParsedData {
+    /// Number of steps from `start` until `is_end` is reached, `None` if it never is.
+    fn steps(&self, start: &Node, is_end: impl Fn(&Node) -> bool) -> Option<u64> {
+        // after being at every node at every instruction, the walk can only go in circles
+        let max_steps = self.map.len() * self.instructions.len();
+        let mut current_node = start;
+
+        let instructions = self.instructions.iter().cycle().take(max_steps);
+        for (num_steps, instruction) in (1..).zip(instructions) {
+            current_node = self.map.get(current_node)?.go(instruction);
+            if is_end(current_node) {
+                return Some(num_steps);
+            }
+        }
+        None
+    }
+}
+
+fn parse(input: &str) -> Result<ParsedData, ParseError> {
+    let instructions = many1(anychar::<_, ErrorTree<&str>>.map_res(Direction::try_from))
+        .terminated(line_ending)
+        .context("instructions");
+    let node = || alphanumeric1.map_res(Node::from_str).context("node");
+    let junction = separated_pair(
+        node(),
+        tag(" = "),
+        delimited(
+            tag("("),
+            separated_pair(node(), tag(", "), node()),
+            tag(")"),
+        ),
+    )
+    .map(|(from, (left, right))| (from, Junction { left, right }));
+    let junctions = lines(junction.context("junction"));
+    let mut parser = separated_pair(instructions, line_ending, junctions);
+    let (instructions, junctions) = finish(input, parser.parse(input))?;
+
+    let junction_lines = input.lines().skip(2).collect::<Vec<_>>();
+    let mut known = HashSet::new();
+    for ((from, _), line) in junctions.iter().zip(&junction_lines) {
+        if !known.insert(from) {
+            return Err(ParseError::invalid(
+                input,
+                line,
+                format!("there already is a junction for {from}"),
+            ));
+        }
+    }
+    for ((_, junction), line) in junctions.iter().zip(&junction_lines) {
+        if let Some(unknown) = [&junction.left, &junction.right]
+            .into_iter()
+            .find(|node| !known.contains(node))
+        {
+            return Err(ParseError::invalid(
+                input,
+                line,
+                format!("there is no junction for {unknown}"),
+            ));
+        }
+    }
+
+    Ok(ParsedData {
+        instructions,
+        map: junctions.into_iter().collect(),
+    })
+}
+
+#[test]
+fn parse_example1() {
+    let example_input = "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)";
+    parse(example_input).unwrap();
+}
+
+#[test]
+fn parse_example2() {
+    let example_input = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+    parse(example_input).unwrap();
+}
+
+#[test]
+fn parse_example3() {
+    let example_input = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+    parse(example_input).unwrap();
+}
+
+#[test]
+fn parse_unknown_node() {
+    let input = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)";
+    let error = parse(input).unwrap_err();
+    assert_eq!(4, error.location().line);
+    assert!(error.to_string().contains("there is no junction for ZZZ"));
+}
+
+#[test]
+fn parse_duplicate_node() {
+    let input = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+AAA = (ZZZ, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+    let error = parse(input).unwrap_err();
+    assert_eq!(5, error.location().line);
+    assert!(error
+        .to_string()
+        .contains("there already is a junction for AAA"));
+}
+
+#[test]
+fn parse_real_input() {
+    let Some(real_input) = aoc_common::input::real_input(8) else {
+        return;
+    };
+    parse(&real_input).unwrap();
+}
@@ -0,0 +1,63 @@
+use crate::{Node, ParsedData};
+
+const START_NODE: Node = Node(['A'; 3]);
+const END_NODE: Node = Node(['Z'; 3]);
+
+/// Number of steps from `AAA` to `ZZZ`, `None` if `ZZZ` can't be reached.
+pub(crate) fn process(input: &ParsedData) -> Option<u64> {
+    input.steps(&START_NODE, |node| *node == END_NODE)
+}
+
+#[test]
+fn example1() {
+    let input = "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)";
+    let expected = 2;
+
+    let parsed = crate::parse(input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(Some(expected), output);
+}
+
+#[test]
+fn example2() {
+    let input = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+    let expected = 6;
+
+    let parsed = crate::parse(input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(Some(expected), output);
+}
+
+#[test]
+fn real_input() {
+    let Some((input, expected)) = aoc_common::known_answers::real_answer(8, 1) else {
+        return;
+    };
+
+    let parsed = crate::parse(&input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(expected, aoc_common::Answer::from(output).to_string());
+}
+
+#[test]
+fn end_unreachable() {
+    let input = "L
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+    let parsed = crate::parse(input).unwrap();
+    assert_eq!(None, process(&parsed));
+}
@@ -0,0 +1,101 @@
+use crate::{Node, ParsedData};
+
+const START_CHAR: char = 'A';
+const END_CHAR: char = 'Z';
+
+/// Number of steps until every ghost is at a node ending with `Z` at the same time, `None` if
+/// there are no ghosts, some ghost never gets to one or if it doesn't fit in a `u64`.
+pub(crate) fn process(input: &ParsedData) -> Option<u64> {
+    // calculate path lengths separately
+    // total is the lowest common multiple (lcm)
+    // lcm of n numbers lcm(a[..n]) = lcm(lcm(a[..n-1], a[n-1]))
+    let mut start_nodes = input.map.keys().filter(|node| node.is_start()).peekable();
+    start_nodes.peek()?;
+    start_nodes.try_fold(1u64, |lcm, node| {
+        let num_steps = input.steps(node, Node::is_end)?;
+        lcm.checked_mul(num_steps / num::integer::gcd(lcm, num_steps))
+    })
+}
+
+impl Node {
+    fn is_start(&self) -> bool {
+        self.0[2] == START_CHAR
+    }
+
+    fn is_end(&self) -> bool {
+        self.0[2] == END_CHAR
+    }
+}
+
+#[test]
+fn example1() {
+    let input = "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)";
+    let expected = 2;
+
+    let parsed = crate::parse(input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(Some(expected), output);
+}
+
+#[test]
+fn example2() {
+    let input = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+    let expected = 6;
+
+    let parsed = crate::parse(input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(Some(expected), output);
+}
+
+#[test]
+fn example3() {
+    let input = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+    let expected = 6;
+
+    let parsed = crate::parse(input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(Some(expected), output);
+}
+
+#[test]
+fn real_input() {
+    let Some((input, expected)) = aoc_common::known_answers::real_answer(8, 2) else {
+        return;
+    };
+
+    let parsed = crate::parse(&input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(expected, aoc_common::Answer::from(output).to_string());
+}
+
+#[test]
+fn no_ghosts() {
+    let input = "LR
+
+BBB = (ZZZ, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+
+    let parsed = crate::parse(input).unwrap();
+    assert_eq!(None, process(&parsed));
+}
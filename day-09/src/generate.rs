@@ -0,0 +1,28 @@
+use aoc_common::generate::Rng;
+
+/// Values in every sequence, like in the real input.
+const LEN: usize = 21;
+
+/// `size` sequences, each with values of a polynomial of degree 6 or less.
+pub(crate) fn input(size: usize, rng: &mut Rng) -> String {
+    let size = size.max(1);
+    let mut out = String::new();
+    for _ in 0..size {
+        // the first value of every derivative, down to the constant one
+        let degree = rng.between(0, 6) as usize;
+        let mut firsts = (0..=degree)
+            .map(|_| rng.between(0, 40) as i64 - 20)
+            .collect::<Vec<_>>();
+
+        let mut values = Vec::with_capacity(LEN);
+        for _ in 0..LEN {
+            values.push(firsts[0].to_string());
+            for idx in 0..degree {
+                firsts[idx] += firsts[idx + 1];
+            }
+        }
+        out.push_str(&values.join(" "));
+        out.push('\n');
+    }
+    out
+}
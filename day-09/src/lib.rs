@@ -0,0 +1,103 @@
+use aoc_common::{
+    generate::Rng,
+    parse::{finish, lines, ParseError},
+    Answer, Solution,
+};
+use nom::{
+    character::complete::{i64, space1},
+    multi::separated_list1,
+    Parser,
+};
+use nom_supreme::{error::ErrorTree, ParserExt};
+
+mod generate;
+mod part1;
+mod part2;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Parsed = ParsedData;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::process(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::process(parsed).into()
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate::input(size, rng))
+    }
+}
+
+type ParsedData = Vec<Sequence>;
+
+#[derive(Debug)]
+pub struct Sequence(Vec<i64>);
+
+impl Sequence {
+    /// The first and last value of the sequence, and of every row of differences below it down
+    /// to the one that's all zeroes. `None` if a difference doesn't fit in an `i64`.
+    fn difference_ends(&self) -> Option<Vec<(i64, i64)>> {
+        let mut ends = Vec::new();
+        // every row of differences is one shorter, so it fits where the row above it was
+        let mut row = self.0.clone();
+        while let (Some(&first), Some(&last)) = (row.first(), row.last()) {
+            if row.iter().all(|&e| e == 0) {
+                break;
+            }
+            ends.push((first, last));
+            for i in 1..row.len() {
+                row[i - 1] = row[i].checked_sub(row[i - 1])?;
+            }
+            row.pop();
+        }
+        Some(ends)
+    }
+}
+
+fn parse(input: &str) -> Result<ParsedData, ParseError> {
+    let sequence = separated_list1(space1::<_, ErrorTree<&str>>, i64)
+        .map(Sequence)
+        .context("sequence");
+    let mut parser = lines(sequence);
+
+    finish(input, parser.parse(input))
+}
+
+#[test]
+fn parse_example() {
+    let example_input = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+    parse(example_input).unwrap();
+}
+
+#[test]
+fn long_sequence() {
+    // every row of differences is the one above it shifted by two, so none of them are all
+    // zeroes and there's a row for every number
+    let numbers = [1, 1, 0, -1, -1, 0].repeat(1_000);
+    let sequence = Sequence(numbers);
+    assert_eq!(6_000, sequence.difference_ends().unwrap().len());
+
+    let parsed = vec![sequence];
+    assert_eq!(Some(0), part1::process(&parsed));
+    assert_eq!(Some(0), part2::process(&parsed));
+}
+
+#[test]
+fn parse_real_input() {
+    let Some(real_input) = aoc_common::input::real_input(9) else {
+        return;
+    };
+    parse(&real_input).unwrap();
+}
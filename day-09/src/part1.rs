@@ -0,0 +1,41 @@
+use crate::{ParsedData, Sequence};
+
+/// Sum of the next values, `None` if it doesn't fit in an `i64`.
+pub(crate) fn process(input: &ParsedData) -> Option<i64> {
+    input
+        .iter()
+        .try_fold(0i64, |sum, seq| sum.checked_add(seq.next_value()?))
+}
+
+impl Sequence {
+    fn next_value(&self) -> Option<i64> {
+        // the next value of every row is its last value plus the next value of the row below
+        self.difference_ends()?
+            .iter()
+            .rev()
+            .try_fold(0i64, |next, &(_, last)| last.checked_add(next))
+    }
+}
+
+#[test]
+fn example() {
+    let input = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+    let expected = 114;
+
+    let parsed = crate::parse(input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(Some(expected), output);
+}
+
+#[test]
+fn real_input() {
+    let Some((input, expected)) = aoc_common::known_answers::real_answer(9, 1) else {
+        return;
+    };
+
+    let parsed = crate::parse(&input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(expected, aoc_common::Answer::from(output).to_string());
+}
@@ -0,0 +1,84 @@
+use aoc_common::{generate::Rng, geometry::Direction, grid::Grid};
+
+use crate::Pipe;
+
+/// A `size` by `size` field of pipes, with a comb shaped loop through most of it.
+///
+/// The loop runs along the top, and down and up again through teeth of random widths and depths,
+/// which enclose the tiles between them. Everything around the loop is random junk pipes and
+/// ground, except next to `S`, so that only the loop connects to it.
+pub(crate) fn input(size: usize, rng: &mut Rng) -> String {
+    let size = size.max(4);
+    // the loop stays a tile away from the edges
+    let (width, height) = (size - 2, size - 2);
+
+    let mut path = Vec::new();
+    let mut left = 0;
+    while left < width {
+        let mut right = left + rng.between(1, 5) as usize;
+        if right + 2 >= width {
+            right = width - 1;
+        }
+        let depth = rng.between(1, height as u64 - 1) as usize;
+
+        path.extend((1..=depth).map(|y| (left, y)));
+        path.extend((left + 1..=right).map(|x| (x, depth)));
+        path.extend((1..depth).rev().map(|y| (right, y)));
+        left = right + 1;
+    }
+    path.extend((0..width).rev().map(|x| (x, 0)));
+    let path = path
+        .into_iter()
+        .map(|(x, y)| (x + 1, y + 1))
+        .collect::<Vec<_>>();
+
+    let mut tiles = Grid::from_fn(size, size, |_| None);
+    for (idx, &coords) in path.iter().enumerate() {
+        let previous = path[(idx + path.len() - 1) % path.len()];
+        let next = path[(idx + 1) % path.len()];
+        tiles[coords] = Some(pipe_char(Pipe(
+            towards(coords, previous),
+            towards(coords, next),
+        )));
+    }
+
+    let start = *rng.pick(&path);
+    tiles[start] = Some('S');
+    for coords in tiles.neighbours4(start).collect::<Vec<_>>() {
+        tiles[coords].get_or_insert('.');
+    }
+
+    let junk = ['|', '-', 'L', 'J', '7', 'F', '.', '.', '.', '.'];
+    tiles
+        .rows()
+        .map(|row| {
+            row.iter()
+                .map(|tile| tile.unwrap_or_else(|| *rng.pick(&junk)))
+                .chain(['\n'])
+                .collect::<String>()
+        })
+        .collect()
+}
+
+/// Direction of the neighbouring tile `to`, seen from `from`.
+fn towards(from: (usize, usize), to: (usize, usize)) -> Direction {
+    match (to.0 as i64 - from.0 as i64, to.1 as i64 - from.1 as i64) {
+        (0, -1) => Direction::North,
+        (1, 0) => Direction::East,
+        (0, 1) => Direction::South,
+        (-1, 0) => Direction::West,
+        offset => unreachable!("{to:?} isn't next to {from:?}, offset {offset:?}"),
+    }
+}
+
+fn pipe_char(pipe: Pipe) -> char {
+    ['|', '-', 'L', 'J', '7', 'F']
+        .into_iter()
+        .find(|&char| {
+            let Ok(Pipe(a, b)) = Pipe::try_from(char) else {
+                return false;
+            };
+            pipe.connects(a) && pipe.connects(b)
+        })
+        .expect("pipes connect two different directions")
+}
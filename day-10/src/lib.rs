@@ -0,0 +1,272 @@
+use aoc_common::{
+    generate::Rng,
+    geometry::Direction,
+    grid::{self, Grid},
+    image::{Draw, Image},
+    parse::ParseError,
+    playback::{Animate, Recording},
+    render::{Glyph, Render},
+    Answer, Solution,
+};
+
+mod generate;
+mod part1;
+mod part2;
+mod render;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Parsed = PipeGrid;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::process(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::process(parsed).into()
+    }
+
+    fn render(parsed: &Self::Parsed) -> Option<Grid<Glyph>> {
+        Some(parsed.render())
+    }
+
+    fn animate(parsed: &Self::Parsed) -> Option<Recording> {
+        Some(parsed.record())
+    }
+
+    fn draw(parsed: &Self::Parsed) -> Option<Image> {
+        Some(parsed.draw())
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate::input(size, rng))
+    }
+}
+
+#[derive(Debug)]
+struct GridWalker<'a> {
+    facing: Direction,
+    position: (usize, usize),
+    grid: &'a PipeGrid,
+}
+
+impl GridWalker<'_> {
+    fn advance(&mut self) {
+        self.try_advance()
+            .expect("walkers stay on the loop, which was checked when parsing")
+    }
+
+    /// Follow the pipe the walker is on, unless it doesn't connect to where the walker came from
+    /// or it leads off the grid.
+    fn try_advance(&mut self) -> Option<()> {
+        match self.grid.pipe(self.position) {
+            Some(Pipe(from, to)) if from == -self.facing => self.move_to(to),
+            Some(Pipe(to, from)) if from == -self.facing => self.move_to(to),
+            _ => None,
+        }
+    }
+
+    /// Move one tile towards `to`, unless that would leave the grid.
+    fn move_to(&mut self, to: Direction) -> Option<()> {
+        self.position = self.grid.grid.moved(self.position, to)?;
+        self.facing = to;
+        Some(())
+    }
+}
+
+fn init_walkers(grid: &PipeGrid) -> (GridWalker<'_>, GridWalker<'_>) {
+    let walker = |facing| {
+        let mut walker = GridWalker {
+            facing,
+            position: grid.start,
+            grid,
+        };
+        walker
+            .move_to(facing)
+            .expect("there are pipes next to the start, which was checked when parsing");
+        walker
+    };
+
+    let Pipe(facing1, facing2) = grid.start_pipe;
+    (walker(facing1), walker(facing2))
+}
+
+#[derive(Debug)]
+pub struct PipeGrid {
+    grid: Grid<Tile>,
+    start: (usize, usize),
+    /// The pipe hidden under the start tile, connecting the two pipes the loop continues in.
+    start_pipe: Pipe,
+}
+
+impl PipeGrid {
+    /// Whether following the pipes out of one side of the start pipe leads back in the other.
+    fn closes_loop(&self) -> bool {
+        let (mut walker, _) = init_walkers(self);
+        while walker.position != self.start {
+            if walker.try_advance().is_none() {
+                return false;
+            }
+        }
+        walker.facing == -self.start_pipe.1
+    }
+
+    /// The pipe at `coords`, if there is one (the start tile doesn't count).
+    fn pipe(&self, coords: (usize, usize)) -> Option<Pipe> {
+        match self.grid.get(coords)? {
+            Tile::Pipe(pipe) => Some(*pipe),
+            Tile::Ground | Tile::Start => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Pipe(Direction, Direction);
+
+impl Pipe {
+    fn connects(&self, direction: Direction) -> bool {
+        self.0 == direction || self.1 == direction
+    }
+
+    /// The box drawing char with the same shape as the pipe.
+    fn box_char(&self) -> char {
+        use Direction::*;
+        match [North, East, South, West].map(|direction| self.connects(direction)) {
+            [true, false, true, false] => '│',
+            [false, true, false, true] => '─',
+            [true, true, false, false] => '└',
+            [true, false, false, true] => '┘',
+            [false, false, true, true] => '┐',
+            [false, true, true, false] => '┌',
+            _ => '?',
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Ground,
+    Start,
+    Pipe(Pipe),
+}
+
+impl TryFrom<char> for Tile {
+    type Error = anyhow::Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Tile::Ground),
+            'S' => Ok(Tile::Start),
+            c => Pipe::try_from(c).map(Tile::Pipe),
+        }
+    }
+}
+
+impl TryFrom<char> for Pipe {
+    type Error = anyhow::Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '|' => Ok(Pipe(Direction::North, Direction::South)),
+            '-' => Ok(Pipe(Direction::West, Direction::East)),
+            'L' => Ok(Pipe(Direction::North, Direction::East)),
+            'J' => Ok(Pipe(Direction::North, Direction::West)),
+            '7' => Ok(Pipe(Direction::South, Direction::West)),
+            'F' => Ok(Pipe(Direction::South, Direction::East)),
+            _ => Err(anyhow::anyhow!("not a pipe char: {value}")),
+        }
+    }
+}
+
+fn parse(input: &str) -> Result<PipeGrid, ParseError> {
+    let grid: Grid<Tile> = input.parse()?;
+
+    let starts = grid
+        .iter()
+        .filter(|&(_, &tile)| tile == Tile::Start)
+        .map(|(coords, _)| coords)
+        .collect::<Vec<_>>();
+    let start = match starts[..] {
+        [start] => start,
+        [] => return Err(ParseError::expected(input, &input[input.len()..], "an 'S'")),
+        [_, second, ..] => return Err(grid::unexpected_cell(second, 'S', "only one 'S'")),
+    };
+
+    let not_on_loop = || grid::invalid_cell(start, 'S', "the start should be on a loop of pipes");
+    let connected = enum_iterator::all::<Direction>()
+        .filter(|&direction| {
+            let pipe = grid
+                .moved(start, direction)
+                .and_then(|coords| match grid[coords] {
+                    Tile::Pipe(pipe) => Some(pipe),
+                    Tile::Ground | Tile::Start => None,
+                });
+            pipe.is_some_and(|pipe| pipe.connects(-direction))
+        })
+        .collect::<Vec<_>>();
+    // more than two pipes can point at the start, but only two of them are on the loop
+    let mut start_pipes = connected.iter().enumerate().flat_map(|(i, &first)| {
+        connected[i + 1..]
+            .iter()
+            .map(move |&second| Pipe(first, second))
+    });
+    let mut pipe_grid = PipeGrid {
+        grid,
+        start,
+        start_pipe: start_pipes.next().ok_or_else(not_on_loop)?,
+    };
+    while !pipe_grid.closes_loop() {
+        pipe_grid.start_pipe = start_pipes.next().ok_or_else(not_on_loop)?;
+    }
+
+    Ok(pipe_grid)
+}
+
+#[test]
+fn parse_two_starts() {
+    let input = ".S-7.
+.|.|.
+.L-S.";
+    let expected = grid::unexpected_cell((3, 2), 'S', "only one 'S'");
+    assert_eq!(expected, parse(input).unwrap_err());
+}
+
+#[test]
+fn parse_start_off_loop() {
+    let input = ".S-7.
+.|.|.
+.L-J.";
+    parse(input).unwrap();
+
+    let input = ".S-7.
+.|.|.
+.L-..";
+    let expected = grid::invalid_cell((1, 0), 'S', "the start should be on a loop of pipes");
+    assert_eq!(expected, parse(input).unwrap_err());
+}
+
+#[test]
+fn parse_extra_pipe_into_start() {
+    // the '|' above the start points into it, but isn't on the loop
+    let input = "..|..
+.FS7.
+.|.|.
+.L-J.";
+    let parsed = parse(input).unwrap();
+    assert_eq!(Pipe(Direction::East, Direction::West), parsed.start_pipe);
+}
+
+#[test]
+fn parse_real_input() {
+    let Some(real_input) = aoc_common::input::real_input(10) else {
+        return;
+    };
+    parse(&real_input).unwrap();
+}
@@ -0,0 +1,73 @@
+use crate::{init_walkers, PipeGrid};
+
+pub(crate) fn process(input: &PipeGrid) -> usize {
+    let (mut walker1, mut walker2) = init_walkers(input);
+    let mut distance = 1;
+
+    while walker1.position != walker2.position {
+        walker1.advance();
+        walker2.advance();
+        distance += 1;
+    }
+
+    distance
+}
+
+#[test]
+fn parse_example() {
+    let example_input = ".....
+.S-7.
+.|.|.
+.L-J.
+.....";
+    crate::parse(example_input).unwrap();
+}
+
+#[test]
+fn parse_example2() {
+    let example_input = "-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF";
+    crate::parse(example_input).unwrap();
+}
+
+#[test]
+fn example() {
+    let input = ".....
+.S-7.
+.|.|.
+.L-J.
+.....";
+    let expected = 4;
+
+    let parsed = crate::parse(input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(expected, output);
+}
+
+#[test]
+fn example2() {
+    let input = "..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...";
+    let expected = 8;
+
+    let parsed = crate::parse(input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(expected, output);
+}
+
+#[test]
+fn real_input() {
+    let Some((input, expected)) = aoc_common::known_answers::real_answer(10, 1) else {
+        return;
+    };
+
+    let parsed = crate::parse(&input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(expected, aoc_common::Answer::from(output).to_string());
+}
@@ -0,0 +1,185 @@
+use aoc_common::{geometry::Direction, grid::Grid};
+
+use crate::{init_walkers, Pipe, PipeGrid};
+
+pub(crate) fn process(input: &PipeGrid) -> usize {
+    let enclosed = enclosed(&main_loop(input));
+    enclosed.iter().filter(|&(_, &enclosed)| enclosed).count()
+}
+
+/// Which tiles are enclosed by the `main_loop`.
+pub(crate) fn enclosed(main_loop: &Grid<Option<Pipe>>) -> Grid<bool> {
+    let mut enclosed = Grid::from_fn(main_loop.width(), main_loop.height(), |_| false);
+
+    // scanning a row from the left, every loop pipe going north crosses the loop once;
+    // '-', 'F' and '7' don't, so that walking along the loop (like 'L--7') crosses it once
+    for (y, row) in main_loop.rows().enumerate() {
+        let mut inside = false;
+        for (x, tile) in row.iter().enumerate() {
+            match tile {
+                Some(pipe) if pipe.connects(Direction::North) => inside = !inside,
+                Some(_) => {}
+                None => enclosed[(x, y)] = inside,
+            }
+        }
+    }
+
+    enclosed
+}
+
+/// The pipes of the loop through the start tile, without any junk pipes.
+pub(crate) fn main_loop(input: &PipeGrid) -> Grid<Option<Pipe>> {
+    let mut main_loop = Grid::from_fn(input.grid.width(), input.grid.height(), |_| None);
+    main_loop[input.start] = Some(input.start_pipe);
+
+    let (mut walker, _) = init_walkers(input);
+    while walker.position != input.start {
+        main_loop[walker.position] = input.pipe(walker.position);
+        walker.advance();
+    }
+
+    main_loop
+}
+
+#[test]
+fn parse_example() {
+    let example_input = "...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........";
+    crate::parse(example_input).unwrap();
+}
+
+#[test]
+fn parse_example2() {
+    let example_input = "..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+..........";
+    crate::parse(example_input).unwrap();
+}
+
+#[test]
+fn parse_example3() {
+    let example_input = ".F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...";
+    crate::parse(example_input).unwrap();
+}
+
+#[test]
+fn parse_example4() {
+    let example_input = "FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L";
+    crate::parse(example_input).unwrap();
+}
+
+#[test]
+fn example() {
+    let input = "...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........";
+    let expected = 4;
+
+    let parsed = crate::parse(input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(expected, output);
+}
+
+#[test]
+fn example2() {
+    let input = "..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+..........";
+    let expected = 4;
+
+    let parsed = crate::parse(input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(expected, output);
+}
+
+#[test]
+fn example3() {
+    let input = ".F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...";
+    let expected = 8;
+
+    let parsed = crate::parse(input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(expected, output);
+}
+
+#[test]
+fn example4() {
+    let input = "FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L";
+    let expected = 10;
+
+    let parsed = crate::parse(input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(expected, output);
+}
+
+#[test]
+fn real_input() {
+    let Some((input, expected)) = aoc_common::known_answers::real_answer(10, 2) else {
+        return;
+    };
+
+    let parsed = crate::parse(&input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(expected, aoc_common::Answer::from(output).to_string());
+}
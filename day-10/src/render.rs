@@ -0,0 +1,213 @@
+use aoc_common::{
+    geometry::{Direction, Point},
+    grid::Grid,
+    image::{self, Draw, Image, Shape},
+    playback::{Animate, Recording},
+    render::{Color, Glyph, Render},
+};
+
+use crate::{init_walkers, part2, Pipe, PipeGrid, Tile};
+
+/// The main loop in bold green, with the tiles it encloses in blue and junk pipes dimmed.
+impl Render for PipeGrid {
+    fn render(&self) -> Grid<Glyph> {
+        let main_loop = part2::main_loop(self);
+        let enclosed = part2::enclosed(&main_loop);
+
+        Grid::from_fn(self.grid.width(), self.grid.height(), |coords| {
+            let glyph = match (self.grid[coords], main_loop[coords]) {
+                (Tile::Start, _) => return Glyph::plain('S').fg(Color::Red).bold(),
+                (_, Some(pipe)) => return Glyph::plain(pipe.box_char()).fg(Color::Green).bold(),
+                (Tile::Pipe(pipe), None) => Glyph::plain(pipe.box_char()).dim(),
+                (Tile::Ground, None) => Glyph::plain('.').dim(),
+            };
+            if enclosed[coords] {
+                glyph.bg(Color::Blue)
+            } else {
+                glyph
+            }
+        })
+    }
+}
+
+/// The two walkers going around the loop in opposite directions, until they meet.
+impl Animate for PipeGrid {
+    fn record(&self) -> Recording {
+        let tile = |coords| match self.grid[coords] {
+            Tile::Start => Glyph::plain('S').fg(Color::Red).bold(),
+            Tile::Pipe(pipe) => Glyph::plain(pipe.box_char()),
+            Tile::Ground => Glyph::plain('.'),
+        };
+        let visited = |coords| tile(coords).fg(Color::Green).bold();
+        let walker = |coords| tile(coords).bg(Color::Red).bold();
+
+        let initial = Grid::from_fn(self.grid.width(), self.grid.height(), |coords| {
+            tile(coords).dim()
+        });
+        let mut recording = Recording::new(initial, "start");
+
+        let (mut walker1, mut walker2) = init_walkers(self);
+        recording.push_changes(
+            vec![
+                (walker1.position, walker(walker1.position)),
+                (walker2.position, walker(walker2.position)),
+            ],
+            "step 1",
+        );
+        let mut step = 1;
+        while walker1.position != walker2.position {
+            let previous = [walker1.position, walker2.position];
+            walker1.advance();
+            walker2.advance();
+            step += 1;
+
+            let changes = previous
+                .into_iter()
+                .map(|coords| (coords, visited(coords)))
+                .chain([walker1.position, walker2.position].map(|coords| (coords, walker(coords))))
+                .collect();
+            recording.push_changes(changes, format!("step {step}"));
+        }
+
+        recording
+    }
+}
+
+/// The main loop as one thick green line around the shaded tiles it encloses, with junk pipes
+/// drawn thin and grey.
+impl Draw for PipeGrid {
+    fn draw(&self) -> Image {
+        let main_loop = part2::main_loop(self);
+        let enclosed = part2::enclosed(&main_loop);
+        let mut image = Image::new(self.grid.width(), self.grid.height());
+
+        for (coords, _) in enclosed.iter().filter(|(_, &enclosed)| enclosed) {
+            image.push(Shape::Shade {
+                coords,
+                color: Color::Blue,
+            });
+        }
+
+        for (coords, tile) in self.grid.iter() {
+            if let (Tile::Pipe(Pipe(from, to)), None) = (tile, main_loop[coords]) {
+                image.push(Shape::Polyline {
+                    points: vec![
+                        edge(coords, *from),
+                        image::center(coords),
+                        edge(coords, *to),
+                    ],
+                    closed: false,
+                    width: 0.1,
+                    color: Color::White,
+                });
+            }
+        }
+
+        let (mut walker, _) = init_walkers(self);
+        let mut points = vec![image::center(self.start)];
+        while walker.position != self.start {
+            points.push(image::center(walker.position));
+            walker.advance();
+        }
+        image.push(Shape::Polyline {
+            points,
+            closed: true,
+            width: 0.3,
+            color: Color::Green,
+        });
+        image.push(Shape::Circle {
+            center: image::center(self.start),
+            radius: 0.3,
+            color: Color::Red,
+        });
+
+        image
+    }
+}
+
+/// Middle of the edge of the tile at `coords` in `direction`, where a pipe leaves the tile.
+fn edge(coords: (usize, usize), direction: Direction) -> (f64, f64) {
+    let (x, y) = image::center(coords);
+    let Point { x: dx, y: dy } = direction.into();
+    (x + dx as f64 / 2.0, y + dy as f64 / 2.0)
+}
+
+#[test]
+fn render_example() {
+    let input = "..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+..........";
+    let expected = "..........
+.S──────┐.
+.│┌────┐│.
+.││....││.
+.││....││.
+.│└─┐┌─┘│.
+.│..││..│.
+.└──┘└──┘.
+..........
+";
+
+    let parsed = crate::parse(input).unwrap();
+    let picture = parsed.render();
+    assert_eq!(expected, aoc_common::render::plain(&picture));
+    assert_eq!(
+        4,
+        picture
+            .iter()
+            .filter(|(_, glyph)| glyph.bg == Some(Color::Blue))
+            .count()
+    );
+}
+
+#[test]
+fn record_example() {
+    let input = ".....
+.S-7.
+.|.|.
+.L-J.
+.....";
+    let recording = crate::parse(input).unwrap().record();
+    // start, then both walkers need 4 steps to meet
+    assert_eq!(5, recording.len());
+    assert_eq!("step 4", recording.label(4));
+}
+
+#[test]
+fn draw_example() {
+    let input = "-F-7
+.|-|
+.L-S";
+    let image = crate::parse(input).unwrap().draw();
+    let shapes = image.shapes();
+
+    // the enclosed tile, the two junk pipes, the loop and the start
+    assert_eq!(5, shapes.len());
+    assert_eq!(
+        Shape::Shade {
+            coords: (2, 1),
+            color: Color::Blue,
+        },
+        shapes[0]
+    );
+    assert_eq!(
+        Shape::Polyline {
+            points: vec![(0.0, 0.5), (0.5, 0.5), (1.0, 0.5)],
+            closed: false,
+            width: 0.1,
+            color: Color::White,
+        },
+        shapes[1]
+    );
+    let Shape::Polyline { points, closed, .. } = &shapes[3] else {
+        panic!("expected the loop, got {:?}", shapes[3]);
+    };
+    assert!(closed);
+    assert_eq!(8, points.len());
+}
@@ -0,0 +1,28 @@
+use aoc_common::generate::Rng;
+
+/// A `size` by `size` universe with about one galaxy in every 50 cells.
+pub(crate) fn input(size: usize, rng: &mut Rng) -> String {
+    universe(size, size, 0.02, rng)
+}
+
+/// A `width` by `height` universe, where every cell is a galaxy with a chance of `density`.
+///
+/// About one in 15 rows and columns are left empty, so that there's something to expand.
+pub(crate) fn universe(width: usize, height: usize, density: f64, rng: &mut Rng) -> String {
+    let empty_rows = (0..height)
+        .map(|_| rng.chance(1.0 / 15.0))
+        .collect::<Vec<_>>();
+    let empty_columns = (0..width)
+        .map(|_| rng.chance(1.0 / 15.0))
+        .collect::<Vec<_>>();
+
+    let mut out = String::new();
+    for empty_row in empty_rows {
+        for &empty_column in &empty_columns {
+            let galaxy = !empty_row && !empty_column && rng.chance(density);
+            out.push(if galaxy { '#' } else { '.' });
+        }
+        out.push('\n');
+    }
+    out
+}
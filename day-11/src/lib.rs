@@ -0,0 +1,177 @@
+use aoc_common::{
+    generate::Rng,
+    geometry::Point,
+    grid::Grid,
+    image::{Draw, Image},
+    parse::ParseError,
+    render::{Glyph, Render},
+    Answer, Solution,
+};
+use itertools::Itertools;
+
+mod generate;
+mod part1;
+mod part2;
+mod render;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Parsed = GalaxyGrid;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::process(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::process(parsed).into()
+    }
+
+    fn render(parsed: &Self::Parsed) -> Option<Grid<Glyph>> {
+        Some(parsed.render())
+    }
+
+    fn draw(parsed: &Self::Parsed) -> Option<Image> {
+        Some(parsed.draw())
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate::input(size, rng))
+    }
+}
+
+#[derive(Debug)]
+pub struct GalaxyGrid {
+    grid: Grid<Space>,
+    empty_rows: Vec<usize>,
+    empty_columns: Vec<usize>,
+}
+
+impl GalaxyGrid {
+    fn galaxies(&self) -> impl Iterator<Item = (usize, usize)> + Clone + '_ {
+        self.grid
+            .iter()
+            .filter(|&(_, &space)| space == Space::Galaxy)
+            .map(|(coords, _)| coords)
+    }
+
+    /// Sum of the distances between every pair of galaxies, `None` if it doesn't fit in a
+    /// `usize`.
+    fn total_distance(&self, expansion: usize) -> Option<usize> {
+        self.galaxies()
+            .tuple_combinations()
+            .try_fold(0usize, |sum, (p1, p2)| {
+                sum.checked_add(self.distance(p1, p2, expansion)?)
+            })
+    }
+
+    /// `None` if the distance doesn't fit in a `usize`.
+    fn distance(&self, p1: (usize, usize), p2: (usize, usize), expansion: usize) -> Option<usize> {
+        let (p1, p2) = (
+            (p1.0.min(p2.0), p1.1.min(p2.1)),
+            (p1.0.max(p2.0), p1.1.max(p2.1)),
+        );
+
+        let base_distance = Point::from(p1).manhattan(p2.into()) as usize;
+
+        let empty_rows = (p1.1..p2.1)
+            .filter(|row| self.empty_rows.contains(row))
+            .count();
+        let empty_columns = (p1.0..p2.0)
+            .filter(|column| self.empty_columns.contains(column))
+            .count();
+
+        // every empty row or column crossed is `expansion` wide instead of 1
+        let extra = (empty_rows + empty_columns).checked_mul(expansion.checked_sub(1)?)?;
+        base_distance.checked_add(extra)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Space {
+    Empty,
+    Galaxy,
+}
+
+impl TryFrom<char> for Space {
+    type Error = anyhow::Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Space::Empty),
+            '#' => Ok(Space::Galaxy),
+            c => Err(anyhow::anyhow!("invalid char {c}")),
+        }
+    }
+}
+
+impl From<Grid<Space>> for GalaxyGrid {
+    fn from(grid: Grid<Space>) -> Self {
+        let empty_rows = grid
+            .rows()
+            .positions(|row| row.iter().all(|&space| space == Space::Empty))
+            .collect();
+        let empty_columns = grid
+            .columns()
+            .positions(|mut column| column.all(|&space| space == Space::Empty))
+            .collect();
+
+        GalaxyGrid {
+            grid,
+            empty_rows,
+            empty_columns,
+        }
+    }
+}
+
+fn parse(input: &str) -> Result<GalaxyGrid, ParseError> {
+    let grid: Grid<Space> = input.parse()?;
+    Ok(grid.into())
+}
+
+#[test]
+fn parse_example() {
+    let example_input = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+    parse(example_input).unwrap();
+}
+
+#[test]
+fn expanded_distances() {
+    let input = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+    let grid = parse(input).unwrap();
+    assert_eq!(Some(1030), grid.total_distance(10));
+    assert_eq!(Some(8410), grid.total_distance(100));
+    assert_eq!(None, grid.total_distance(usize::MAX));
+}
+
+#[test]
+fn parse_real_input() {
+    let Some(real_input) = aoc_common::input::real_input(11) else {
+        return;
+    };
+    parse(&real_input).unwrap();
+}
@@ -0,0 +1,20 @@
+use crate::GalaxyGrid;
+
+const EXPANSION: usize = 1_000_000;
+
+/// Sum of the distances between every pair of galaxies once empty space is a million times as
+/// wide, `None` if it doesn't fit in a `usize`.
+pub(crate) fn process(input: &GalaxyGrid) -> Option<usize> {
+    input.total_distance(EXPANSION)
+}
+
+#[test]
+fn real_input() {
+    let Some((input, expected)) = aoc_common::known_answers::real_answer(11, 2) else {
+        return;
+    };
+
+    let parsed = crate::parse(&input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(expected, aoc_common::Answer::from(output).to_string());
+}
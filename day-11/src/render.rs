@@ -0,0 +1,61 @@
+use aoc_common::{
+    grid::Grid,
+    image::{self, Draw, Image, Shape},
+    render::{Color, Glyph, Render},
+};
+
+use crate::{GalaxyGrid, Space};
+
+/// Galaxies in bold yellow, on a blue background where the universe expands.
+impl Render for GalaxyGrid {
+    fn render(&self) -> Grid<Glyph> {
+        Grid::from_fn(self.grid.width(), self.grid.height(), |(x, y)| {
+            let glyph = match self.grid[(x, y)] {
+                Space::Galaxy => Glyph::plain('#').fg(Color::Yellow).bold(),
+                Space::Empty => Glyph::plain('.').dim(),
+            };
+            if self.empty_rows.contains(&y) || self.empty_columns.contains(&x) {
+                glyph.bg(Color::Blue)
+            } else {
+                glyph
+            }
+        })
+    }
+}
+
+/// Galaxies as yellow circles, on shaded rows and columns where the universe expands.
+impl Draw for GalaxyGrid {
+    fn draw(&self) -> Image {
+        let mut image = Image::new(self.grid.width(), self.grid.height());
+        for (coords @ (x, y), _) in self.grid.iter() {
+            if self.empty_rows.contains(&y) || self.empty_columns.contains(&x) {
+                image.push(Shape::Shade {
+                    coords,
+                    color: Color::Blue,
+                });
+            }
+        }
+        for coords in self.galaxies() {
+            image.push(Shape::Circle {
+                center: image::center(coords),
+                radius: 0.35,
+                color: Color::Yellow,
+            });
+        }
+        image
+    }
+}
+
+#[test]
+fn render_example() {
+    let input = "#..
+...
+..#";
+    let picture = crate::parse(input).unwrap().render();
+    let expanded = picture
+        .iter()
+        .filter(|(_, glyph)| glyph.bg == Some(Color::Blue))
+        .map(|(coords, _)| coords)
+        .collect::<Vec<_>>();
+    assert_eq!(vec![(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)], expanded);
+}
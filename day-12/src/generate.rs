@@ -0,0 +1,41 @@
+use aoc_common::generate::Rng;
+
+/// Rows in the generated input.
+const ROWS: usize = 100;
+
+/// Rows of `size` springs, with about a fifth of them unknown.
+///
+/// The rows are made from known springs first, so that there is at least one arrangement that
+/// matches the counts of broken springs.
+pub(crate) fn input(size: usize, rng: &mut Rng) -> String {
+    let size = size.max(1);
+    let mut out = String::new();
+    for _ in 0..ROWS {
+        let mut springs = String::new();
+        let mut counts = Vec::new();
+        while springs.len() < size {
+            let operational = rng.between(u64::from(!springs.is_empty()), 3) as usize;
+            springs.extend(std::iter::repeat_n(
+                '.',
+                operational.min(size - springs.len()),
+            ));
+
+            let broken = rng.between(1, 4).min((size - springs.len()) as u64) as usize;
+            if broken > 0 {
+                springs.extend(std::iter::repeat_n('#', broken));
+                counts.push(broken.to_string());
+            }
+        }
+        if counts.is_empty() {
+            springs.replace_range(..1, "#");
+            counts.push("1".to_string());
+        }
+
+        let springs = springs
+            .chars()
+            .map(|spring| if rng.chance(0.2) { '?' } else { spring })
+            .collect::<String>();
+        out.push_str(&format!("{springs} {}\n", counts.join(",")));
+    }
+    out
+}
@@ -0,0 +1,247 @@
+use aoc_common::{
+    generate::Rng,
+    parse::{finish, lines, ParseError},
+    Answer, Solution,
+};
+use nom::{
+    character::complete::{anychar, char, u32},
+    multi::{many1, separated_list1},
+    sequence::separated_pair,
+    Parser,
+};
+use nom_supreme::{error::ErrorTree, ParserExt};
+use std::collections::HashMap;
+
+mod generate;
+mod part1;
+mod part2;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Parsed = ParsedData;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::process(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::process(parsed).into()
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate::input(size, rng))
+    }
+}
+
+type ParsedData = Vec<SpringRow>;
+
+#[derive(Debug, Clone)]
+pub struct SpringRow {
+    row: Vec<SpringStatus>,
+    broken_counts: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SpringStatus {
+    Working,
+    Broken,
+    Unknown,
+}
+
+impl TryFrom<char> for SpringStatus {
+    type Error = anyhow::Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(SpringStatus::Working),
+            '#' => Ok(SpringStatus::Broken),
+            '?' => Ok(SpringStatus::Unknown),
+            _ => Err(anyhow::anyhow!("invalid char")),
+        }
+    }
+}
+
+impl SpringRow {
+    /// The row repeated `times` times, joined by unknown springs.
+    fn unfold(&self, times: usize) -> SpringRow {
+        let row = vec![self.row.clone(); times].join(&SpringStatus::Unknown);
+        let broken_counts = self.broken_counts.repeat(times);
+        SpringRow { row, broken_counts }
+    }
+
+    /// Number of ways the unknown springs can be filled in to match the broken counts, `None` if
+    /// it doesn't fit in a `u64`.
+    ///
+    /// Goes through the row one spring at a time, keeping how many ways there are to get to
+    /// `run` broken springs in the group `self.broken_counts[group]`. A count is `None` once it
+    /// doesn't fit in a `u64`, which only matters if its ways can still match the whole row.
+    fn arrangements(&self) -> Option<u64> {
+        let n_groups = self.broken_counts.len();
+        let mut states: HashMap<(usize, usize), Option<u64>> = HashMap::from([((0, 0), Some(1))]);
+
+        for &status in &self.row {
+            let mut next = HashMap::with_capacity(states.len());
+            let mut add = |state, count: Option<u64>| {
+                let total: &mut Option<u64> = next.entry(state).or_insert(Some(0));
+                *total = total
+                    .zip(count)
+                    .and_then(|(total, count)| total.checked_add(count));
+            };
+
+            for (&(group, run), &count) in &states {
+                let wanted = self.broken_counts.get(group).copied();
+                if status != SpringStatus::Working && wanted.is_some_and(|wanted| run < wanted) {
+                    // continue (or start) the group
+                    add((group, run + 1), count);
+                }
+                if status != SpringStatus::Broken {
+                    if run == 0 {
+                        add((group, 0), count);
+                    } else if Some(run) == wanted {
+                        // end the group
+                        add((group + 1, 0), count);
+                    }
+                }
+            }
+            states = next;
+        }
+
+        // the last group has to be done
+        states
+            .into_iter()
+            .filter(|&((group, run), _)| match run {
+                0 => group == n_groups,
+                _ => group + 1 == n_groups && run == self.broken_counts[group],
+            })
+            .try_fold(0u64, |total, (_, count)| total.checked_add(count?))
+    }
+}
+
+fn parse(input: &str) -> Result<ParsedData, ParseError> {
+    let counts = separated_list1(char(','), u32.map(|n| n as usize)).context("broken counts");
+    let springs = many1(anychar.map_res(SpringStatus::try_from)).context("springs");
+    let row = separated_pair(springs, char(' '), counts)
+        .map(|(row, broken_counts)| SpringRow { row, broken_counts });
+    let mut parser = lines(row.context("spring row"));
+
+    finish::<_, ErrorTree<_>>(input, parser(input))
+}
+
+#[test]
+fn parse_example() {
+    let example_input = "???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+    parse(example_input).unwrap();
+}
+
+#[test]
+fn long_row() {
+    let row = SpringRow {
+        row: vec![SpringStatus::Unknown; 100_000],
+        broken_counts: vec![1, 1],
+    };
+    assert_eq!(Some(99_999 * 99_998 / 2), row.arrangements());
+}
+
+#[test]
+fn parse_real_input() {
+    let Some(real_input) = aoc_common::input::real_input(12) else {
+        return;
+    };
+    parse(&real_input).unwrap();
+}
+
+#[cfg(test)]
+impl SpringRow {
+    /// [`SpringRow::arrangements`] the slow way, by trying every combination of unknown springs.
+    fn brute_force_arrangements(&self) -> u64 {
+        let unknowns = self
+            .row
+            .iter()
+            .filter(|&&status| status == SpringStatus::Unknown)
+            .count();
+
+        (0..1u64 << unknowns)
+            .filter(|combination| {
+                let mut unknown_idx = 0;
+                let filled_in = self.row.iter().map(|&status| match status {
+                    SpringStatus::Unknown => {
+                        unknown_idx += 1;
+                        combination >> (unknown_idx - 1) & 1 == 1
+                    }
+                    status => status == SpringStatus::Broken,
+                });
+                let counts = filled_in
+                    .collect::<Vec<_>>()
+                    .split(|&broken| !broken)
+                    .map(<[bool]>::len)
+                    .filter(|&len| len > 0)
+                    .collect::<Vec<_>>();
+                counts == self.broken_counts
+            })
+            .count() as u64
+    }
+}
+
+/// Rows of up to 14 springs, either with the counts of a filled in version of the row, so that
+/// there is at least one arrangement, or with random counts.
+#[cfg(test)]
+fn spring_row() -> impl proptest::strategy::Strategy<Value = SpringRow> {
+    use proptest::{collection::vec, prelude::*};
+
+    let matching = vec((any::<bool>(), any::<bool>()), 1..14).prop_map(|springs| {
+        let broken_counts = springs
+            .split(|&(broken, _)| !broken)
+            .map(<[_]>::len)
+            .filter(|&len| len > 0)
+            .collect();
+        let row = springs
+            .into_iter()
+            .map(|(broken, unknown)| match (broken, unknown) {
+                (_, true) => SpringStatus::Unknown,
+                (true, false) => SpringStatus::Broken,
+                (false, false) => SpringStatus::Working,
+            })
+            .collect();
+        SpringRow { row, broken_counts }
+    });
+    let status = prop_oneof![
+        Just(SpringStatus::Working),
+        Just(SpringStatus::Broken),
+        Just(SpringStatus::Unknown),
+    ];
+    let random = (vec(status, 1..14), vec(1usize..5, 1..5))
+        .prop_map(|(row, broken_counts)| SpringRow { row, broken_counts });
+
+    prop_oneof![matching, random]
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn same_as_brute_force(row in spring_row()) {
+        proptest::prop_assert_eq!(Some(row.brute_force_arrangements()), row.arrangements());
+    }
+
+    #[test]
+    fn unfolded_same_as_brute_force(row in spring_row()) {
+        // unfolding twice keeps the number of combinations small enough to try them all
+        let row = SpringRow {
+            row: row.row.into_iter().take(6).collect(),
+            ..row
+        }
+        .unfold(2);
+        proptest::prop_assert_eq!(Some(row.brute_force_arrangements()), row.arrangements());
+    }
+}
@@ -0,0 +1,34 @@
+use crate::ParsedData;
+
+/// Sum of the arrangements of every row, `None` if it doesn't fit in a `u64`.
+pub(crate) fn process(input: &ParsedData) -> Option<u64> {
+    input
+        .iter()
+        .try_fold(0u64, |sum, row| sum.checked_add(row.arrangements()?))
+}
+
+#[test]
+fn example() {
+    let input = "???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+    let expected = 21;
+
+    let parsed = crate::parse(input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(Some(expected), output);
+}
+
+#[test]
+fn real_input() {
+    let Some((input, expected)) = aoc_common::known_answers::real_answer(12, 1) else {
+        return;
+    };
+
+    let parsed = crate::parse(&input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(expected, aoc_common::Answer::from(output).to_string());
+}
@@ -0,0 +1,43 @@
+use crate::ParsedData;
+
+const UNFOLD: usize = 5;
+
+/// Sum of the arrangements of every unfolded row, `None` if it doesn't fit in a `u64`.
+pub(crate) fn process(input: &ParsedData) -> Option<u64> {
+    input.iter().try_fold(0u64, |sum, row| {
+        sum.checked_add(row.unfold(UNFOLD).arrangements()?)
+    })
+}
+
+#[test]
+fn example() {
+    let input = "???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+    let expected = 525152;
+
+    let parsed = crate::parse(input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(Some(expected), output);
+}
+
+#[test]
+fn real_input() {
+    let Some((input, expected)) = aoc_common::known_answers::real_answer(12, 2) else {
+        return;
+    };
+
+    let parsed = crate::parse(&input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(expected, aoc_common::Answer::from(output).to_string());
+}
+
+#[test]
+fn arrangements_too_large() {
+    let input = format!("{} {}", "?".repeat(100), ["1"; 10].join(","));
+    let parsed = crate::parse(&input).unwrap();
+    assert_eq!(None, process(&parsed));
+}
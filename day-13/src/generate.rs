@@ -0,0 +1,44 @@
+use aoc_common::generate::Rng;
+
+/// `size` patterns, each with a mirror that reflects it exactly, and one that reflects it with a
+/// single smudge.
+///
+/// Every pattern starts out as rows `a b b a` followed by random rows, where `b` is `a` with one
+/// cell flipped. The rows are mirrored at the middle of those 4 rows, and at the line between
+/// `a` and `b` with one smudge. The patterns are then randomly flipped upside down and
+/// transposed, to move the mirrors around.
+pub(crate) fn input(size: usize, rng: &mut Rng) -> String {
+    let size = size.max(1);
+    let patterns = (0..size)
+        .map(|_| {
+            let width = rng.between(5, 17) as usize;
+            let random_row = |rng: &mut Rng| {
+                (0..width)
+                    .map(|_| if rng.chance(0.5) { '#' } else { '.' })
+                    .collect::<Vec<_>>()
+            };
+
+            let a = random_row(rng);
+            let mut b = a.clone();
+            let smudge = rng.below(width as u64) as usize;
+            b[smudge] = if b[smudge] == '#' { '.' } else { '#' };
+            let mut rows = vec![a.clone(), b.clone(), b, a];
+            for _ in 0..rng.between(1, 10) {
+                rows.push(random_row(rng));
+            }
+
+            if rng.chance(0.5) {
+                rows.reverse();
+            }
+            if rng.chance(0.5) {
+                rows = (0..width)
+                    .map(|x| rows.iter().map(|row| row[x]).collect())
+                    .collect();
+            }
+            rows.iter()
+                .map(|row| row.iter().chain(['\n'].iter()).collect::<String>())
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>();
+    patterns.join("\n")
+}
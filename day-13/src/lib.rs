@@ -0,0 +1,150 @@
+use aoc_common::{
+    generate::Rng,
+    parse::{finish, ParseError},
+    Answer, Solution,
+};
+use nom::{
+    character::complete::{anychar, line_ending},
+    combinator::opt,
+    multi::{many1, separated_list1},
+    sequence::pair,
+    Parser,
+};
+use nom_supreme::{error::ErrorTree, ParserExt};
+
+mod generate;
+mod part1;
+mod part2;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Parsed = Vec<Pattern>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::process(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::process(parsed).into()
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate::input(size, rng))
+    }
+}
+
+#[derive(Debug)]
+pub struct Pattern {
+    rows: Vec<Vec<Ground>>,
+}
+
+impl Pattern {
+    fn columns(&self) -> Vec<Vec<Ground>> {
+        let width = self.rows.first().map_or(0, Vec::len);
+        (0..width)
+            .map(|x| self.rows.iter().map(|row| row[x]).collect())
+            .collect()
+    }
+
+    /// Columns left of the vertical reflection line, or 100 times the rows above the horizontal
+    /// one, where the reflection is off by exactly `smudges` tiles.
+    fn summary(&self, smudges: usize) -> Option<usize> {
+        reflection(&self.columns(), smudges)
+            .or_else(|| reflection(&self.rows, smudges).map(|rows| 100 * rows))
+    }
+}
+
+/// Number of `lines` before the line they are mirrored at, with exactly `smudges` differences.
+fn reflection(lines: &[Vec<Ground>], smudges: usize) -> Option<usize> {
+    (1..lines.len()).find(|&mirror| {
+        let (before, after) = lines.split_at(mirror);
+        let differences = before
+            .iter()
+            .rev()
+            .zip(after)
+            .map(|(line1, line2)| line1.iter().zip(line2).filter(|(a, b)| a != b).count())
+            .sum::<usize>();
+        differences == smudges
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Ground {
+    Ash,
+    Rock,
+}
+
+impl TryFrom<char> for Ground {
+    type Error = anyhow::Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Ground::Ash),
+            '#' => Ok(Ground::Rock),
+            c => Err(anyhow::anyhow!("invalid char {c}")),
+        }
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Pattern>, ParseError> {
+    let row = many1(anychar.map_res(Ground::try_from)).context("row");
+    let pattern = separated_list1(line_ending, row)
+        .map_res_cut(|rows: Vec<Vec<Ground>>| {
+            let width = rows.first().map_or(0, Vec::len);
+            if rows.iter().any(|row| row.len() != width) {
+                return Err(format!("rows should all be {width} wide"));
+            }
+            Ok(Pattern { rows })
+        })
+        .context("pattern");
+    let mut parser = separated_list1(pair(line_ending, line_ending), pattern)
+        .terminated(opt(line_ending::<_, ErrorTree<&str>>));
+
+    finish(input, parser.parse(input))
+}
+
+#[test]
+fn parse_example() {
+    let example_input = "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+    let parsed = parse(example_input).unwrap();
+    assert_eq!(2, parsed.len());
+}
+
+#[test]
+fn parse_uneven_rows() {
+    let input = "#.##
+..#
+
+#...";
+    let error = parse(input).unwrap_err();
+    assert!(error.to_string().contains("rows should all be 4 wide"));
+}
+
+#[test]
+fn parse_real_input() {
+    let Some(real_input) = aoc_common::input::real_input(13) else {
+        return;
+    };
+    parse(&real_input).unwrap();
+}
@@ -0,0 +1,54 @@
+use crate::Pattern;
+
+const SMUDGES: usize = 1;
+
+/// Sum of the summaries of every pattern, `None` if a pattern has no reflection line once its smudge is cleaned.
+pub(crate) fn process(input: &[Pattern]) -> Option<usize> {
+    input.iter().try_fold(0usize, |sum, pattern| {
+        sum.checked_add(pattern.summary(SMUDGES)?)
+    })
+}
+
+#[test]
+fn example() {
+    let input = "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+    let expected = 400;
+
+    let parsed = crate::parse(input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(Some(expected), output);
+}
+
+#[test]
+fn no_reflection() {
+    let input = "#.#
+.#.";
+
+    let parsed = crate::parse(input).unwrap();
+    assert_eq!(None, process(&parsed));
+}
+
+#[test]
+fn real_input() {
+    let Some((input, expected)) = aoc_common::known_answers::real_answer(13, 2) else {
+        return;
+    };
+
+    let parsed = crate::parse(&input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(expected, aoc_common::Answer::from(output).to_string());
+}
@@ -0,0 +1,17 @@
+use aoc_common::generate::Rng;
+
+/// A `size` by `size` platform, with about a fifth round rocks and a tenth cube rocks.
+pub(crate) fn input(size: usize, rng: &mut Rng) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        for _ in 0..size {
+            out.push(match rng.below(10) {
+                0 | 1 => 'O',
+                2 => '#',
+                _ => '.',
+            });
+        }
+        out.push('\n');
+    }
+    out
+}
@@ -0,0 +1,208 @@
+use aoc_common::{
+    generate::Rng,
+    geometry::Direction,
+    grid::Grid,
+    image::{Draw, Image},
+    parse::ParseError,
+    playback::{Animate, Recording},
+    render::{Glyph, Render},
+    Answer, Solution,
+};
+use std::collections::HashMap;
+
+mod generate;
+mod part1;
+mod part2;
+mod render;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Parsed = RockGrid;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+
+    fn part1(parsed: &Self::Parsed) -> Answer {
+        part1::process(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed) -> Answer {
+        part2::process(parsed).into()
+    }
+
+    fn render(parsed: &Self::Parsed) -> Option<Grid<Glyph>> {
+        Some(parsed.render())
+    }
+
+    fn animate(parsed: &Self::Parsed) -> Option<Recording> {
+        Some(parsed.record())
+    }
+
+    fn draw(parsed: &Self::Parsed) -> Option<Image> {
+        Some(parsed.draw())
+    }
+
+    fn generate(size: usize, rng: &mut Rng) -> Option<String> {
+        Some(generate::input(size, rng))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RockGrid {
+    grid: Grid<Tile>,
+}
+
+impl RockGrid {
+    fn tilt(&mut self, dir: Direction) {
+        // every line of tiles the rocks roll along, starting at the side they roll to
+        let edge = self
+            .grid
+            .iter()
+            .map(|(coords, _)| coords)
+            .filter(|&coords| self.grid.moved(coords, dir).is_none());
+        let lines: Vec<Vec<_>> = edge
+            .map(|start| {
+                std::iter::successors(Some(start), |&coords| self.grid.moved(coords, -dir))
+                    .collect()
+            })
+            .collect();
+
+        for line in lines {
+            self.roll(&line);
+        }
+    }
+
+    /// Roll all round rocks on `line` to its start, until they hit a square rock.
+    fn roll(&mut self, line: &[(usize, usize)]) {
+        let mut free = 0;
+        for (idx, &coords) in line.iter().enumerate() {
+            match self.grid[coords] {
+                Tile::Empty => {}
+                Tile::Square => free = idx + 1,
+                Tile::Round => {
+                    self.grid[coords] = Tile::Empty;
+                    self.grid[line[free]] = Tile::Round;
+                    free += 1;
+                }
+            }
+        }
+    }
+
+    fn total_load(&self) -> usize {
+        let height = self.grid.height();
+        self.grid
+            .iter()
+            .filter(|&(_, &tile)| tile == Tile::Round)
+            .map(|((_, y), _)| height - y)
+            .sum::<usize>()
+    }
+
+    /// Tilt north, west, south and east.
+    pub fn spin_cycle(&mut self) {
+        self.tilt(Direction::North);
+        self.tilt(Direction::West);
+        self.tilt(Direction::South);
+        self.tilt(Direction::East);
+    }
+
+    /// After how many spin cycles the grid starts repeating itself, and how often.
+    pub fn periodicity(&self) -> Periodicity {
+        self.spin_until_repeat().1
+    }
+
+    /// The grids after 0, 1, 2, ... spin cycles, up to the first repeated one.
+    fn spin_until_repeat(&self) -> (Vec<RockGrid>, Periodicity) {
+        let mut seen = HashMap::new();
+        let mut grids = Vec::new();
+        let mut grid = self.clone();
+
+        loop {
+            if let Some(&start) = seen.get(&grid) {
+                let period = grids.len() - start;
+                return (grids, Periodicity { start, period });
+            }
+            seen.insert(grid.clone(), grids.len());
+            grids.push(grid.clone());
+            grid.spin_cycle();
+        }
+    }
+}
+
+/// The grid after `start + period` spin cycles is the same as after `start` spin cycles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Periodicity {
+    pub start: usize,
+    pub period: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Tile {
+    Empty,
+    Square,
+    Round,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = anyhow::Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Tile::Empty),
+            '#' => Ok(Tile::Square),
+            'O' => Ok(Tile::Round),
+            c => Err(anyhow::anyhow!("invalid char {c}")),
+        }
+    }
+}
+
+fn parse(input: &str) -> Result<RockGrid, ParseError> {
+    Ok(RockGrid {
+        grid: input.parse()?,
+    })
+}
+
+#[test]
+fn example_periodicity() {
+    let input = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+    let expected = Periodicity {
+        start: 3,
+        period: 7,
+    };
+    assert_eq!(expected, parse(input).unwrap().periodicity());
+}
+
+#[test]
+fn parse_example() {
+    let example_input = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+    parse(example_input).unwrap();
+}
+
+#[test]
+fn parse_real_input() {
+    let Some(real_input) = aoc_common::input::real_input(14) else {
+        return;
+    };
+    parse(&real_input).unwrap();
+}
@@ -0,0 +1,39 @@
+use aoc_common::geometry::Direction;
+
+use crate::RockGrid;
+
+pub(crate) fn process(input: &RockGrid) -> usize {
+    let mut grid = input.clone();
+    grid.tilt(Direction::North);
+    grid.total_load()
+}
+
+#[test]
+fn example() {
+    let input = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+    let expected = 136;
+
+    let parsed = crate::parse(input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(expected, output);
+}
+
+#[test]
+fn real_input() {
+    let Some((input, expected)) = aoc_common::known_answers::real_answer(14, 1) else {
+        return;
+    };
+
+    let parsed = crate::parse(&input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(expected, aoc_common::Answer::from(output).to_string());
+}
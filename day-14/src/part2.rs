@@ -0,0 +1,45 @@
+use crate::{Periodicity, RockGrid};
+
+const N_CYCLES: usize = 1_000_000_000;
+
+pub(crate) fn process(input: &RockGrid) -> usize {
+    let (grids, Periodicity { start, period }) = input.spin_until_repeat();
+
+    // after `start` cycles, the grids repeat every `period` cycles
+    let idx = if N_CYCLES < grids.len() {
+        N_CYCLES
+    } else {
+        start + (N_CYCLES - start) % period
+    };
+    grids[idx].total_load()
+}
+
+#[test]
+fn example() {
+    let input = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+    let expected = 64;
+
+    let parsed = crate::parse(input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(expected, output);
+}
+
+#[test]
+fn real_input() {
+    let Some((input, expected)) = aoc_common::known_answers::real_answer(14, 2) else {
+        return;
+    };
+
+    let parsed = crate::parse(&input).unwrap();
+    let output = process(&parsed);
+    assert_eq!(expected, aoc_common::Answer::from(output).to_string());
+}
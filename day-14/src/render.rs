@@ -0,0 +1,120 @@
+use aoc_common::{
+    geometry::Direction,
+    grid::Grid,
+    image::{self, Draw, Image, Shape},
+    playback::{Animate, Recording},
+    render::{Color, Glyph, Render},
+};
+
+use crate::{Periodicity, RockGrid, Tile};
+
+/// Round rocks in bold yellow and cube rocks in white.
+impl Render for RockGrid {
+    fn render(&self) -> Grid<Glyph> {
+        self.grid.map(|tile| match tile {
+            Tile::Empty => Glyph::plain('.').dim(),
+            Tile::Square => Glyph::plain('#').fg(Color::White),
+            Tile::Round => Glyph::plain('O').fg(Color::Yellow).bold(),
+        })
+    }
+}
+
+/// Every tilt of the spin cycles, until the grid starts repeating itself.
+impl Animate for RockGrid {
+    fn record(&self) -> Recording {
+        let Periodicity { start, period } = self.periodicity();
+
+        let mut grid = self.clone();
+        let mut recording = Recording::new(grid.render(), "start");
+        for cycle in 1..=start + period {
+            for dir in [
+                Direction::North,
+                Direction::West,
+                Direction::South,
+                Direction::East,
+            ] {
+                grid.tilt(dir);
+                recording.push(grid.render(), format!("cycle {cycle}, tilted {dir:?}"));
+            }
+        }
+
+        recording
+    }
+}
+
+/// Round rocks as yellow circles and cube rocks as dark squares.
+impl Draw for RockGrid {
+    fn draw(&self) -> Image {
+        let mut image = Image::new(self.grid.width(), self.grid.height());
+        for (coords, tile) in self.grid.iter() {
+            let center = image::center(coords);
+            match tile {
+                Tile::Empty => continue,
+                Tile::Square => image.push(Shape::Square {
+                    center,
+                    size: 0.9,
+                    color: Color::Black,
+                }),
+                Tile::Round => image.push(Shape::Circle {
+                    center,
+                    radius: 0.4,
+                    color: Color::Yellow,
+                }),
+            }
+        }
+        image
+    }
+}
+
+#[test]
+fn render_example() {
+    let input = "O.#
+.O.";
+    let picture = crate::parse(input).unwrap().render();
+    assert_eq!("O.#\n.O.\n", aoc_common::render::plain(&picture));
+}
+
+#[test]
+fn draw_example() {
+    let input = "O.#
+.O.";
+    let image = crate::parse(input).unwrap().draw();
+    assert_eq!(
+        [
+            Shape::Circle {
+                center: (0.5, 0.5),
+                radius: 0.4,
+                color: Color::Yellow,
+            },
+            Shape::Square {
+                center: (2.5, 0.5),
+                size: 0.9,
+                color: Color::Black,
+            },
+            Shape::Circle {
+                center: (1.5, 1.5),
+                radius: 0.4,
+                color: Color::Yellow,
+            },
+        ],
+        image.shapes()
+    );
+}
+
+#[test]
+fn record_example() {
+    let input = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+    let recording = crate::parse(input).unwrap().record();
+    // repeats after 3 + 7 cycles
+    assert_eq!(1 + 10 * 4, recording.len());
+    assert_eq!("cycle 10, tilted East", recording.label(40));
+}
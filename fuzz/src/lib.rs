@@ -0,0 +1,19 @@
+//! Fuzz targets for the parsers and solutions of every day, run with `cargo fuzz run day-05`
+//! from the repository root (on nightly).
+//!
+//! Parsing should never panic, whatever the input is, and neither should solving the parts of an
+//! input that could be parsed.
+
+/// Parse `data` as the input of `day`, and solve both parts if it could be parsed.
+pub fn parse_and_solve(day: u8, data: &[u8]) {
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+    let solution = aoc::solutions::get(day).expect("every fuzz target is for a solved day");
+
+    if let Ok(parsed) = solution.parse(input) {
+        for part in [1, 2] {
+            solution.solve(parsed.as_ref(), part);
+        }
+    }
+}